@@ -5,8 +5,10 @@
 
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'api.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `get_secret`
+// These functions are ignored because they are not marked as `pub`: `add_stream`, `bind_receiver`, `export`, `get_secret`, `import_bytes`, `import_file`, `import_stream`, `inspect`, `interrupted`, `item_names`, `new`, `new_share`, `open_in`, `open_remote_file`, `read_info`, `read_seq_and_meta`, `receive`, `receive_share`, `received_share`, `restore`, `select_items`, `send`, `serve`, `serve_until_done`, `share`, `share_items`, `share_on`, `write_blob`
 
 Stream<String> startSend({required String filePath, required String dataDir}) =>
     RustLib.instance.api.crateApiStartSend(
@@ -14,6 +16,246 @@ Stream<String> startSend({required String filePath, required String dataDir}) =>
       dataDir: dataDir,
     );
 
+Stream<String> startSendWithOptions({
+  required String filePath,
+  required String dataDir,
+  required SendOptions options,
+}) => RustLib.instance.api.crateApiStartSendWithOptions(
+  filePath: filePath,
+  dataDir: dataDir,
+  options: options,
+);
+
+/// Shares a string like a link or a note. Receivers get it back as a
+/// `TEXT:<text>` event instead of a file.
+Stream<String> startSendText({required String text, required String dataDir}) =>
+    RustLib.instance.api.crateApiStartSendText(text: text, dataDir: dataDir);
+
+Stream<String> startSendTextWithOptions({
+  required String text,
+  required String dataDir,
+  required SendOptions options,
+}) => RustLib.instance.api.crateApiStartSendTextWithOptions(
+  text: text,
+  dataDir: dataDir,
+  options: options,
+);
+
+/// Shares bytes handed over from Dart, e.g. from a share intent, without
+/// writing them to a file first.
+Stream<String> startSendBytes({
+  required String name,
+  required Uint8List data,
+  required String dataDir,
+}) => RustLib.instance.api.crateApiStartSendBytes(
+  name: name,
+  data: data,
+  dataDir: dataDir,
+);
+
+Stream<String> startSendBytesWithOptions({
+  required String name,
+  required Uint8List data,
+  required String dataDir,
+  required SendOptions options,
+}) => RustLib.instance.api.crateApiStartSendBytesWithOptions(
+  name: name,
+  data: data,
+  dataDir: dataDir,
+  options: options,
+);
+
+/// Opens a stream for `start_send_stream`, returning its id. Feed it with
+/// `push_send_chunk` and end it with `finish_send_stream`.
+String createSendStream() => RustLib.instance.api.crateApiCreateSendStream();
+
+Future<void> pushSendChunk({
+  required String streamId,
+  required Uint8List chunk,
+}) => RustLib.instance.api.crateApiPushSendChunk(
+  streamId: streamId,
+  chunk: chunk,
+);
+
+void finishSendStream({required String streamId}) =>
+    RustLib.instance.api.crateApiFinishSendStream(streamId: streamId);
+
+/// Shares the content of a stream from `create_send_stream`, imported into
+/// the store while it is being pushed. The ticket comes once it's finished.
+Stream<String> startSendStream({
+  required String streamId,
+  required String name,
+  required String dataDir,
+}) => RustLib.instance.api.crateApiStartSendStream(
+  streamId: streamId,
+  name: name,
+  dataDir: dataDir,
+);
+
+Stream<String> startSendStreamWithOptions({
+  required String streamId,
+  required String name,
+  required String dataDir,
+  required SendOptions options,
+}) => RustLib.instance.api.crateApiStartSendStreamWithOptions(
+  streamId: streamId,
+  name: name,
+  dataDir: dataDir,
+  options: options,
+);
+
+/// Shares several files at once. Receivers can pick which of them to
+/// download, see `ReceiveOptions::select_indices`.
+Stream<String> startSendFiles({
+  required List<String> filePaths,
+  required String dataDir,
+}) => RustLib.instance.api.crateApiStartSendFiles(
+  filePaths: filePaths,
+  dataDir: dataDir,
+);
+
+Stream<String> startSendFilesWithOptions({
+  required List<String> filePaths,
+  required String dataDir,
+  required SendOptions options,
+}) => RustLib.instance.api.crateApiStartSendFilesWithOptions(
+  filePaths: filePaths,
+  dataDir: dataDir,
+  options: options,
+);
+
+Stream<String> reshare({required String hash, required String dataDir}) =>
+    RustLib.instance.api.crateApiReshare(hash: hash, dataDir: dataDir);
+
+/// Shares received content on, straight from the store and with its original
+/// metadata, so nothing is imported again. `hash` is the hash of the share as
+/// in `TransferRecord::hash`. The password option is ignored, an encrypted
+/// share keeps the password it was sent with.
+Stream<String> reshareWithOptions({
+  required String hash,
+  required String dataDir,
+  required SendOptions options,
+}) => RustLib.instance.api.crateApiReshareWithOptions(
+  hash: hash,
+  dataDir: dataDir,
+  options: options,
+);
+
+/// Stops serving all shares until `resume_sharing`, e.g. when the app goes
+/// to the background. Endpoints are closed, shares and their tickets stay.
+void pauseSharing() => RustLib.instance.api.crateApiPauseSharing();
+
+/// Serves all shares again after `pause_sharing`, on new endpoints with the
+/// same NodeIds, so the tickets handed out keep working.
+void resumeSharing() => RustLib.instance.api.crateApiResumeSharing();
+
+/// Tells how every share being served is doing, e.g. to check whether
+/// `resume_sharing` is needed after the app returns to the foreground.
+List<ShareHealth> checkSharing() => RustLib.instance.api.crateApiCheckSharing();
+
+/// Serves the saved shares again, with the tickets they had. Call once the
+/// app starts, events of all shares go to `sink`, each share starting with
+/// `SHARE_RESTORED:<id>`. Returns once they are all started, before they
+/// are bound, each share's `Ready!` follows on `sink`. Content of older
+/// versions is merged in the background, `MIGRATION_SKIPPED:<hash>` follows
+/// for every blob that couldn't be, or with the name of a whole old store.
+Stream<String> init({required String dataDir}) =>
+    RustLib.instance.api.crateApiInit(dataDir: dataDir);
+
+Future<List<ShareStatus>> listShares({required String dataDir}) =>
+    RustLib.instance.api.crateApiListShares(dataDir: dataDir);
+
+/// Stops serving a share and forgets it, if it was saved. Its content stays
+/// in the store.
+Future<void> stopShare({required String dataDir, required String id}) =>
+    RustLib.instance.api.crateApiStopShare(dataDir: dataDir, id: id);
+
+void respondToPeer({required String nodeId, required bool approve}) =>
+    RustLib.instance.api.crateApiRespondToPeer(
+      nodeId: nodeId,
+      approve: approve,
+    );
+
+/// Fetches only the metadata of a share, so the user can decide whether to
+/// download it before spending bandwidth and storage on it.
+Future<TicketInfo> inspectTicket({
+  required String ticketStr,
+  required String dataDir,
+  String? password,
+}) => RustLib.instance.api.crateApiInspectTicket(
+  ticketStr: ticketStr,
+  dataDir: dataDir,
+  password: password,
+);
+
+/// Lists past transfers newest first, optionally of one direction only.
+Future<List<TransferRecord>> listTransfers({
+  required String dataDir,
+  TransferDirection? direction,
+  required int offset,
+  int? limit,
+}) => RustLib.instance.api.crateApiListTransfers(
+  dataDir: dataDir,
+  direction: direction,
+  offset: offset,
+  limit: limit,
+);
+
+Future<void> deleteTransfer({required String dataDir, required BigInt id}) =>
+    RustLib.instance.api.crateApiDeleteTransfer(dataDir: dataDir, id: id);
+
+/// Deletes all past transfers and returns how many there were. The files
+/// themselves stay where they are.
+Future<BigInt> clearTransfers({required String dataDir}) =>
+    RustLib.instance.api.crateApiClearTransfers(dataDir: dataDir);
+
+/// Lists the tags in the store, all or those starting with `prefix`, e.g.
+/// `send/` for everything shared.
+Future<List<ContentTag>> listTags({required String dataDir, String? prefix}) =>
+    RustLib.instance.api.crateApiListTags(dataDir: dataDir, prefix: prefix);
+
+/// Keeps the content of a tag until it is unpinned by removing the returned
+/// `pin/` tag, whatever happens to the original.
+Future<String> pinTag({required String dataDir, required String name}) =>
+    RustLib.instance.api.crateApiPinTag(dataDir: dataDir, name: name);
+
+/// Removes a tag. The store doesn't collect garbage, so content without any
+/// tags left stays on disk until the data dir is cleared.
+Future<void> removeTag({required String dataDir, required String name}) =>
+    RustLib.instance.api.crateApiRemoveTag(dataDir: dataDir, name: name);
+
+/// Reads `len` bytes at `offset` of a file in a share, the one at `index` or
+/// the first, downloading only the chunks that cover them.
+Future<Uint8List> readRemoteRange({
+  required String ticketStr,
+  required String dataDir,
+  int? index,
+  required BigInt offset,
+  required BigInt len,
+}) => RustLib.instance.api.crateApiReadRemoteRange(
+  ticketStr: ticketStr,
+  dataDir: dataDir,
+  index: index,
+  offset: offset,
+  len: len,
+);
+
+/// Serves a file of a share over HTTP on localhost, so a media player can
+/// play it while it downloads. Emits `STREAM_URL:<url>` once listening and
+/// runs until `stop_stream_server` is called with that URL.
+Stream<String> startStreamServer({
+  required String ticketStr,
+  required String dataDir,
+  int? index,
+}) => RustLib.instance.api.crateApiStartStreamServer(
+  ticketStr: ticketStr,
+  dataDir: dataDir,
+  index: index,
+);
+
+void stopStreamServer({required String url}) =>
+    RustLib.instance.api.crateApiStopStreamServer(url: url);
+
 Stream<String> receiveFile({
   required String ticketStr,
   required String dataDir,
@@ -23,3 +265,419 @@ Stream<String> receiveFile({
   dataDir: dataDir,
   downloadDir: downloadDir,
 );
+
+Stream<String> receiveFileWithOptions({
+  required String ticketStr,
+  required String dataDir,
+  required String downloadDir,
+  required ReceiveOptions options,
+}) => RustLib.instance.api.crateApiReceiveFileWithOptions(
+  ticketStr: ticketStr,
+  dataDir: dataDir,
+  downloadDir: downloadDir,
+  options: options,
+);
+
+/// Receives into memory instead of a file, for previews and small files.
+Future<Uint8List> receiveBytes({
+  required String ticketStr,
+  required String dataDir,
+  required ReceiveOptions options,
+  required RustStreamSink<String> sink,
+}) => RustLib.instance.api.crateApiReceiveBytes(
+  ticketStr: ticketStr,
+  dataDir: dataDir,
+  options: options,
+  sink: sink,
+);
+
+/// Streams the received file to `chunks` instead of saving it, leaving the
+/// destination to the app, e.g. MediaStore or Photos.
+Future<void> receiveToStream({
+  required String ticketStr,
+  required String dataDir,
+  required ReceiveOptions options,
+  required RustStreamSink<Uint8List> chunks,
+  required RustStreamSink<String> sink,
+}) => RustLib.instance.api.crateApiReceiveToStream(
+  ticketStr: ticketStr,
+  dataDir: dataDir,
+  options: options,
+  chunks: chunks,
+  sink: sink,
+);
+
+/// A tag in the store. Tagged content is kept, see `remove_tag`.
+class ContentTag {
+  /// `send/<unix ms>/<name>`, `recv/<hash>` or `pin/<hash>`.
+  final String name;
+  final String hash;
+  /// Whether the tag is for a whole share rather than a single blob.
+  final bool isShare;
+
+  const ContentTag({
+    required this.name,
+    required this.hash,
+    required this.isShare,
+  });
+
+  @override
+  int get hashCode => name.hashCode ^ hash.hashCode ^ isShare.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ContentTag &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          hash == other.hash &&
+          isShare == other.isShare;
+}
+
+/// Why a call failed. Calls with a sink also emit `ERROR_CODE:<code>` and
+/// `Error: <message>` there. Codes never change, so the app can localize
+/// them and pick a recovery, the message is English and for logs.
+@freezed
+sealed class HoleError with _$HoleError implements FrbException {
+  const HoleError._();
+
+  /// Anything not covered below.
+  const factory HoleError.internal(String field0) = HoleError_Internal;
+  const factory HoleError.invalidTicket(String field0) =
+      HoleError_InvalidTicket;
+  /// The ticket is from a newer version of the app, which needs an update.
+  const factory HoleError.unsupportedVersion(String field0) =
+      HoleError_UnsupportedVersion;
+  /// An argument like a NodeId, hash or file selection is invalid.
+  const factory HoleError.invalidInput(String field0) = HoleError_InvalidInput;
+  /// The sender can't be reached, or the connection broke.
+  const factory HoleError.peerUnreachable(String field0) =
+      HoleError_PeerUnreachable;
+  const factory HoleError.timeout(String field0) = HoleError_Timeout;
+  /// Received data doesn't match its hash, it was corrupted on the way.
+  const factory HoleError.hashMismatch(String field0) = HoleError_HashMismatch;
+  const factory HoleError.diskFull(String field0) = HoleError_DiskFull;
+  const factory HoleError.permissionDenied(String field0) =
+      HoleError_PermissionDenied;
+  const factory HoleError.passwordRequired(String field0) =
+      HoleError_PasswordRequired;
+  const factory HoleError.wrongPassword(String field0) =
+      HoleError_WrongPassword;
+  /// The sender's metadata doesn't make sense.
+  const factory HoleError.metadataInvalid(String field0) =
+      HoleError_MetadataInvalid;
+  /// A file, share, tag, stream or the like doesn't exist.
+  const factory HoleError.notFound(String field0) = HoleError_NotFound;
+  const factory HoleError.tooLarge(String field0) = HoleError_TooLarge;
+  /// Valid, but not possible with this content, e.g. streaming an encrypted share.
+  const factory HoleError.unsupported(String field0) = HoleError_Unsupported;
+  /// The app closed a stream the call was using.
+  const factory HoleError.cancelled(String field0) = HoleError_Cancelled;
+  /// The sender refused, e.g. the receiver isn't on its allowlist or a
+  /// one-time share was used up.
+  const factory HoleError.accessDenied(String field0) = HoleError_AccessDenied;
+
+  int code() => RustLib.instance.api.crateApiHoleErrorCode(that: this);
+
+  String message() => RustLib.instance.api.crateApiHoleErrorMessage(that: this);
+}
+
+class ReceiveOptions {
+  /// Password for shares that were sent encrypted.
+  final String? password;
+  /// More providers of the same content, as tickets or NodeIds. With any
+  /// of these the download is spread over all providers in parallel.
+  final List<String> extraSources;
+  /// How often to re-dial and resume after a failed connection or transfer. Defaults to 5.
+  final int? maxRetries;
+  /// Delay before the first retry, doubled on every further one. Defaults to 1000.
+  final BigInt? retryDelayMs;
+  /// Only download these files of a share, by position. Together with
+  /// `select_paths`; both empty means everything.
+  final Uint32List selectIndices;
+  /// Only download the files with these relative paths.
+  final List<String> selectPaths;
+
+  const ReceiveOptions({
+    required this.password,
+    required this.extraSources,
+    required this.maxRetries,
+    required this.retryDelayMs,
+    required this.selectIndices,
+    required this.selectPaths,
+  });
+
+  static Future<ReceiveOptions> default_() =>
+      RustLib.instance.api.crateApiReceiveOptionsDefault();
+
+  @override
+  int get hashCode =>
+      password.hashCode ^
+      extraSources.hashCode ^
+      maxRetries.hashCode ^
+      retryDelayMs.hashCode ^
+      selectIndices.hashCode ^
+      selectPaths.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReceiveOptions &&
+          runtimeType == other.runtimeType &&
+          password == other.password &&
+          extraSources == other.extraSources &&
+          maxRetries == other.maxRetries &&
+          retryDelayMs == other.retryDelayMs &&
+          selectIndices == other.selectIndices &&
+          selectPaths == other.selectPaths;
+}
+
+class SendOptions {
+  /// Encrypt the file and its metadata with a key derived from this password.
+  final String? password;
+  /// Only serve to these NodeIds. Empty means everyone, unless prompting.
+  final List<String> allowlist;
+  /// Emit `PEER_REQUEST:<node_id>` for peers not on the allowlist and wait
+  /// for `respond_to_peer` instead of denying them outright.
+  final bool promptUnknownPeers;
+  /// Close the share after this many completed downloads, e.g. 1 for one-time tickets.
+  final int? maxDownloads;
+  /// Close the share after this many seconds.
+  final BigInt? expiresAfterSecs;
+  /// Save the share, so `init` serves it again under the same ticket after
+  /// the app restarts, until it expires, reaches its limit or is stopped
+  /// with `stop_share`.
+  final bool persistent;
+
+  const SendOptions({
+    required this.password,
+    required this.allowlist,
+    required this.promptUnknownPeers,
+    required this.maxDownloads,
+    required this.expiresAfterSecs,
+    required this.persistent,
+  });
+
+  static Future<SendOptions> default_() =>
+      RustLib.instance.api.crateApiSendOptionsDefault();
+
+  @override
+  int get hashCode =>
+      password.hashCode ^
+      allowlist.hashCode ^
+      promptUnknownPeers.hashCode ^
+      maxDownloads.hashCode ^
+      expiresAfterSecs.hashCode ^
+      persistent.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SendOptions &&
+          runtimeType == other.runtimeType &&
+          password == other.password &&
+          allowlist == other.allowlist &&
+          promptUnknownPeers == other.promptUnknownPeers &&
+          maxDownloads == other.maxDownloads &&
+          expiresAfterSecs == other.expiresAfterSecs &&
+          persistent == other.persistent;
+}
+
+class ShareHealth {
+  final String id;
+  final bool paused;
+  /// Whether the endpoint is bound and reachable, through a relay or directly.
+  final bool reachable;
+  final String? relayUrl;
+  final List<String> directAddrs;
+
+  const ShareHealth({
+    required this.id,
+    required this.paused,
+    required this.reachable,
+    required this.relayUrl,
+    required this.directAddrs,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      paused.hashCode ^
+      reachable.hashCode ^
+      relayUrl.hashCode ^
+      directAddrs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ShareHealth &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          paused == other.paused &&
+          reachable == other.reachable &&
+          relayUrl == other.relayUrl &&
+          directAddrs == other.directAddrs;
+}
+
+/// A saved share, see `SendOptions::persistent`.
+class ShareStatus {
+  final String id;
+  /// The latest ticket. Earlier ones keep working, they have the same NodeId.
+  final String? ticket;
+  final List<String> filenames;
+  final int downloads;
+  final int? maxDownloads;
+  /// Unix time in milliseconds.
+  final BigInt? expiresAtMs;
+  /// Whether it is being served right now.
+  final bool serving;
+
+  const ShareStatus({
+    required this.id,
+    required this.ticket,
+    required this.filenames,
+    required this.downloads,
+    required this.maxDownloads,
+    required this.expiresAtMs,
+    required this.serving,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      ticket.hashCode ^
+      filenames.hashCode ^
+      downloads.hashCode ^
+      maxDownloads.hashCode ^
+      expiresAtMs.hashCode ^
+      serving.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ShareStatus &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          ticket == other.ticket &&
+          filenames == other.filenames &&
+          downloads == other.downloads &&
+          maxDownloads == other.maxDownloads &&
+          expiresAtMs == other.expiresAtMs &&
+          serving == other.serving;
+}
+
+class TicketInfo {
+  /// NodeId of the sender.
+  final String sender;
+  final int version;
+  /// Whether the share needs a password. Without one, nothing but the
+  /// sender and the version is known.
+  final bool encrypted;
+  final bool isText;
+  /// One per file, in the order `ReceiveOptions::select_indices` refers to.
+  final List<String> filenames;
+  final List<String> mimeTypes;
+  /// Total size of the content, if the sender told it.
+  final BigInt? totalSize;
+
+  const TicketInfo({
+    required this.sender,
+    required this.version,
+    required this.encrypted,
+    required this.isText,
+    required this.filenames,
+    required this.mimeTypes,
+    required this.totalSize,
+  });
+
+  @override
+  int get hashCode =>
+      sender.hashCode ^
+      version.hashCode ^
+      encrypted.hashCode ^
+      isText.hashCode ^
+      filenames.hashCode ^
+      mimeTypes.hashCode ^
+      totalSize.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TicketInfo &&
+          runtimeType == other.runtimeType &&
+          sender == other.sender &&
+          version == other.version &&
+          encrypted == other.encrypted &&
+          isText == other.isText &&
+          filenames == other.filenames &&
+          mimeTypes == other.mimeTypes &&
+          totalSize == other.totalSize;
+}
+
+enum TransferDirection { send, receive }
+
+enum TransferOutcome { completed, failed }
+
+/// A past transfer. Sends are recorded per download, receives per call.
+class TransferRecord {
+  final BigInt id;
+  final TransferDirection direction;
+  /// NodeId of the other side.
+  final String peer;
+  /// Hash of the share, as in its ticket.
+  final String hash;
+  final List<String> filenames;
+  /// Sizes of the files, 0 where unknown.
+  final Uint64List sizes;
+  final List<String> fileHashes;
+  /// Unix time in milliseconds.
+  final BigInt startedAtMs;
+  final BigInt durationMs;
+  final TransferOutcome outcome;
+  final String? error;
+
+  const TransferRecord({
+    required this.id,
+    required this.direction,
+    required this.peer,
+    required this.hash,
+    required this.filenames,
+    required this.sizes,
+    required this.fileHashes,
+    required this.startedAtMs,
+    required this.durationMs,
+    required this.outcome,
+    required this.error,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      direction.hashCode ^
+      peer.hashCode ^
+      hash.hashCode ^
+      filenames.hashCode ^
+      sizes.hashCode ^
+      fileHashes.hashCode ^
+      startedAtMs.hashCode ^
+      durationMs.hashCode ^
+      outcome.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TransferRecord &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          direction == other.direction &&
+          peer == other.peer &&
+          hash == other.hash &&
+          filenames == other.filenames &&
+          sizes == other.sizes &&
+          fileHashes == other.fileHashes &&
+          startedAtMs == other.startedAtMs &&
+          durationMs == other.durationMs &&
+          outcome == other.outcome &&
+          error == other.error;
+}
@@ -0,0 +1,1197 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'api.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$HoleError {
+
+ String get field0;
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HoleErrorCopyWith<HoleError> get copyWith => _$HoleErrorCopyWithImpl<HoleError>(this as HoleError, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HoleError&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'HoleError(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HoleErrorCopyWith<$Res>  {
+  factory $HoleErrorCopyWith(HoleError value, $Res Function(HoleError) _then) = _$HoleErrorCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HoleErrorCopyWithImpl<$Res>
+    implements $HoleErrorCopyWith<$Res> {
+  _$HoleErrorCopyWithImpl(this._self, this._then);
+
+  final HoleError _self;
+  final $Res Function(HoleError) _then;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? field0 = null,}) {
+  return _then(_self.copyWith(
+field0: null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+}
+
+/// @nodoc
+
+
+class HoleError_Internal extends HoleError {
+  const HoleError_Internal(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HoleError_InternalCopyWith<HoleError_Internal> get copyWith => _$HoleError_InternalCopyWithImpl<HoleError_Internal>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HoleError_Internal&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'HoleError.internal(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HoleError_InternalCopyWith<$Res> implements $HoleErrorCopyWith<$Res> {
+  factory $HoleError_InternalCopyWith(HoleError_Internal value, $Res Function(HoleError_Internal) _then) = _$HoleError_InternalCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HoleError_InternalCopyWithImpl<$Res>
+    implements $HoleError_InternalCopyWith<$Res> {
+  _$HoleError_InternalCopyWithImpl(this._self, this._then);
+
+  final HoleError_Internal _self;
+  final $Res Function(HoleError_Internal) _then;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HoleError_Internal(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class HoleError_InvalidTicket extends HoleError {
+  const HoleError_InvalidTicket(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HoleError_InvalidTicketCopyWith<HoleError_InvalidTicket> get copyWith => _$HoleError_InvalidTicketCopyWithImpl<HoleError_InvalidTicket>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HoleError_InvalidTicket&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'HoleError.invalidTicket(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HoleError_InvalidTicketCopyWith<$Res> implements $HoleErrorCopyWith<$Res> {
+  factory $HoleError_InvalidTicketCopyWith(HoleError_InvalidTicket value, $Res Function(HoleError_InvalidTicket) _then) = _$HoleError_InvalidTicketCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HoleError_InvalidTicketCopyWithImpl<$Res>
+    implements $HoleError_InvalidTicketCopyWith<$Res> {
+  _$HoleError_InvalidTicketCopyWithImpl(this._self, this._then);
+
+  final HoleError_InvalidTicket _self;
+  final $Res Function(HoleError_InvalidTicket) _then;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HoleError_InvalidTicket(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class HoleError_UnsupportedVersion extends HoleError {
+  const HoleError_UnsupportedVersion(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HoleError_UnsupportedVersionCopyWith<HoleError_UnsupportedVersion> get copyWith => _$HoleError_UnsupportedVersionCopyWithImpl<HoleError_UnsupportedVersion>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HoleError_UnsupportedVersion&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'HoleError.unsupportedVersion(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HoleError_UnsupportedVersionCopyWith<$Res> implements $HoleErrorCopyWith<$Res> {
+  factory $HoleError_UnsupportedVersionCopyWith(HoleError_UnsupportedVersion value, $Res Function(HoleError_UnsupportedVersion) _then) = _$HoleError_UnsupportedVersionCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HoleError_UnsupportedVersionCopyWithImpl<$Res>
+    implements $HoleError_UnsupportedVersionCopyWith<$Res> {
+  _$HoleError_UnsupportedVersionCopyWithImpl(this._self, this._then);
+
+  final HoleError_UnsupportedVersion _self;
+  final $Res Function(HoleError_UnsupportedVersion) _then;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HoleError_UnsupportedVersion(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class HoleError_InvalidInput extends HoleError {
+  const HoleError_InvalidInput(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HoleError_InvalidInputCopyWith<HoleError_InvalidInput> get copyWith => _$HoleError_InvalidInputCopyWithImpl<HoleError_InvalidInput>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HoleError_InvalidInput&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'HoleError.invalidInput(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HoleError_InvalidInputCopyWith<$Res> implements $HoleErrorCopyWith<$Res> {
+  factory $HoleError_InvalidInputCopyWith(HoleError_InvalidInput value, $Res Function(HoleError_InvalidInput) _then) = _$HoleError_InvalidInputCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HoleError_InvalidInputCopyWithImpl<$Res>
+    implements $HoleError_InvalidInputCopyWith<$Res> {
+  _$HoleError_InvalidInputCopyWithImpl(this._self, this._then);
+
+  final HoleError_InvalidInput _self;
+  final $Res Function(HoleError_InvalidInput) _then;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HoleError_InvalidInput(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class HoleError_PeerUnreachable extends HoleError {
+  const HoleError_PeerUnreachable(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HoleError_PeerUnreachableCopyWith<HoleError_PeerUnreachable> get copyWith => _$HoleError_PeerUnreachableCopyWithImpl<HoleError_PeerUnreachable>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HoleError_PeerUnreachable&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'HoleError.peerUnreachable(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HoleError_PeerUnreachableCopyWith<$Res> implements $HoleErrorCopyWith<$Res> {
+  factory $HoleError_PeerUnreachableCopyWith(HoleError_PeerUnreachable value, $Res Function(HoleError_PeerUnreachable) _then) = _$HoleError_PeerUnreachableCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HoleError_PeerUnreachableCopyWithImpl<$Res>
+    implements $HoleError_PeerUnreachableCopyWith<$Res> {
+  _$HoleError_PeerUnreachableCopyWithImpl(this._self, this._then);
+
+  final HoleError_PeerUnreachable _self;
+  final $Res Function(HoleError_PeerUnreachable) _then;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HoleError_PeerUnreachable(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class HoleError_Timeout extends HoleError {
+  const HoleError_Timeout(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HoleError_TimeoutCopyWith<HoleError_Timeout> get copyWith => _$HoleError_TimeoutCopyWithImpl<HoleError_Timeout>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HoleError_Timeout&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'HoleError.timeout(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HoleError_TimeoutCopyWith<$Res> implements $HoleErrorCopyWith<$Res> {
+  factory $HoleError_TimeoutCopyWith(HoleError_Timeout value, $Res Function(HoleError_Timeout) _then) = _$HoleError_TimeoutCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HoleError_TimeoutCopyWithImpl<$Res>
+    implements $HoleError_TimeoutCopyWith<$Res> {
+  _$HoleError_TimeoutCopyWithImpl(this._self, this._then);
+
+  final HoleError_Timeout _self;
+  final $Res Function(HoleError_Timeout) _then;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HoleError_Timeout(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class HoleError_HashMismatch extends HoleError {
+  const HoleError_HashMismatch(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HoleError_HashMismatchCopyWith<HoleError_HashMismatch> get copyWith => _$HoleError_HashMismatchCopyWithImpl<HoleError_HashMismatch>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HoleError_HashMismatch&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'HoleError.hashMismatch(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HoleError_HashMismatchCopyWith<$Res> implements $HoleErrorCopyWith<$Res> {
+  factory $HoleError_HashMismatchCopyWith(HoleError_HashMismatch value, $Res Function(HoleError_HashMismatch) _then) = _$HoleError_HashMismatchCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HoleError_HashMismatchCopyWithImpl<$Res>
+    implements $HoleError_HashMismatchCopyWith<$Res> {
+  _$HoleError_HashMismatchCopyWithImpl(this._self, this._then);
+
+  final HoleError_HashMismatch _self;
+  final $Res Function(HoleError_HashMismatch) _then;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HoleError_HashMismatch(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class HoleError_DiskFull extends HoleError {
+  const HoleError_DiskFull(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HoleError_DiskFullCopyWith<HoleError_DiskFull> get copyWith => _$HoleError_DiskFullCopyWithImpl<HoleError_DiskFull>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HoleError_DiskFull&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'HoleError.diskFull(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HoleError_DiskFullCopyWith<$Res> implements $HoleErrorCopyWith<$Res> {
+  factory $HoleError_DiskFullCopyWith(HoleError_DiskFull value, $Res Function(HoleError_DiskFull) _then) = _$HoleError_DiskFullCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HoleError_DiskFullCopyWithImpl<$Res>
+    implements $HoleError_DiskFullCopyWith<$Res> {
+  _$HoleError_DiskFullCopyWithImpl(this._self, this._then);
+
+  final HoleError_DiskFull _self;
+  final $Res Function(HoleError_DiskFull) _then;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HoleError_DiskFull(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class HoleError_PermissionDenied extends HoleError {
+  const HoleError_PermissionDenied(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HoleError_PermissionDeniedCopyWith<HoleError_PermissionDenied> get copyWith => _$HoleError_PermissionDeniedCopyWithImpl<HoleError_PermissionDenied>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HoleError_PermissionDenied&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'HoleError.permissionDenied(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HoleError_PermissionDeniedCopyWith<$Res> implements $HoleErrorCopyWith<$Res> {
+  factory $HoleError_PermissionDeniedCopyWith(HoleError_PermissionDenied value, $Res Function(HoleError_PermissionDenied) _then) = _$HoleError_PermissionDeniedCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HoleError_PermissionDeniedCopyWithImpl<$Res>
+    implements $HoleError_PermissionDeniedCopyWith<$Res> {
+  _$HoleError_PermissionDeniedCopyWithImpl(this._self, this._then);
+
+  final HoleError_PermissionDenied _self;
+  final $Res Function(HoleError_PermissionDenied) _then;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HoleError_PermissionDenied(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class HoleError_PasswordRequired extends HoleError {
+  const HoleError_PasswordRequired(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HoleError_PasswordRequiredCopyWith<HoleError_PasswordRequired> get copyWith => _$HoleError_PasswordRequiredCopyWithImpl<HoleError_PasswordRequired>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HoleError_PasswordRequired&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'HoleError.passwordRequired(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HoleError_PasswordRequiredCopyWith<$Res> implements $HoleErrorCopyWith<$Res> {
+  factory $HoleError_PasswordRequiredCopyWith(HoleError_PasswordRequired value, $Res Function(HoleError_PasswordRequired) _then) = _$HoleError_PasswordRequiredCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HoleError_PasswordRequiredCopyWithImpl<$Res>
+    implements $HoleError_PasswordRequiredCopyWith<$Res> {
+  _$HoleError_PasswordRequiredCopyWithImpl(this._self, this._then);
+
+  final HoleError_PasswordRequired _self;
+  final $Res Function(HoleError_PasswordRequired) _then;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HoleError_PasswordRequired(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class HoleError_WrongPassword extends HoleError {
+  const HoleError_WrongPassword(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HoleError_WrongPasswordCopyWith<HoleError_WrongPassword> get copyWith => _$HoleError_WrongPasswordCopyWithImpl<HoleError_WrongPassword>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HoleError_WrongPassword&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'HoleError.wrongPassword(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HoleError_WrongPasswordCopyWith<$Res> implements $HoleErrorCopyWith<$Res> {
+  factory $HoleError_WrongPasswordCopyWith(HoleError_WrongPassword value, $Res Function(HoleError_WrongPassword) _then) = _$HoleError_WrongPasswordCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HoleError_WrongPasswordCopyWithImpl<$Res>
+    implements $HoleError_WrongPasswordCopyWith<$Res> {
+  _$HoleError_WrongPasswordCopyWithImpl(this._self, this._then);
+
+  final HoleError_WrongPassword _self;
+  final $Res Function(HoleError_WrongPassword) _then;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HoleError_WrongPassword(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class HoleError_MetadataInvalid extends HoleError {
+  const HoleError_MetadataInvalid(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HoleError_MetadataInvalidCopyWith<HoleError_MetadataInvalid> get copyWith => _$HoleError_MetadataInvalidCopyWithImpl<HoleError_MetadataInvalid>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HoleError_MetadataInvalid&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'HoleError.metadataInvalid(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HoleError_MetadataInvalidCopyWith<$Res> implements $HoleErrorCopyWith<$Res> {
+  factory $HoleError_MetadataInvalidCopyWith(HoleError_MetadataInvalid value, $Res Function(HoleError_MetadataInvalid) _then) = _$HoleError_MetadataInvalidCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HoleError_MetadataInvalidCopyWithImpl<$Res>
+    implements $HoleError_MetadataInvalidCopyWith<$Res> {
+  _$HoleError_MetadataInvalidCopyWithImpl(this._self, this._then);
+
+  final HoleError_MetadataInvalid _self;
+  final $Res Function(HoleError_MetadataInvalid) _then;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HoleError_MetadataInvalid(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class HoleError_NotFound extends HoleError {
+  const HoleError_NotFound(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HoleError_NotFoundCopyWith<HoleError_NotFound> get copyWith => _$HoleError_NotFoundCopyWithImpl<HoleError_NotFound>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HoleError_NotFound&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'HoleError.notFound(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HoleError_NotFoundCopyWith<$Res> implements $HoleErrorCopyWith<$Res> {
+  factory $HoleError_NotFoundCopyWith(HoleError_NotFound value, $Res Function(HoleError_NotFound) _then) = _$HoleError_NotFoundCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HoleError_NotFoundCopyWithImpl<$Res>
+    implements $HoleError_NotFoundCopyWith<$Res> {
+  _$HoleError_NotFoundCopyWithImpl(this._self, this._then);
+
+  final HoleError_NotFound _self;
+  final $Res Function(HoleError_NotFound) _then;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HoleError_NotFound(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class HoleError_TooLarge extends HoleError {
+  const HoleError_TooLarge(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HoleError_TooLargeCopyWith<HoleError_TooLarge> get copyWith => _$HoleError_TooLargeCopyWithImpl<HoleError_TooLarge>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HoleError_TooLarge&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'HoleError.tooLarge(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HoleError_TooLargeCopyWith<$Res> implements $HoleErrorCopyWith<$Res> {
+  factory $HoleError_TooLargeCopyWith(HoleError_TooLarge value, $Res Function(HoleError_TooLarge) _then) = _$HoleError_TooLargeCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HoleError_TooLargeCopyWithImpl<$Res>
+    implements $HoleError_TooLargeCopyWith<$Res> {
+  _$HoleError_TooLargeCopyWithImpl(this._self, this._then);
+
+  final HoleError_TooLarge _self;
+  final $Res Function(HoleError_TooLarge) _then;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HoleError_TooLarge(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class HoleError_Unsupported extends HoleError {
+  const HoleError_Unsupported(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HoleError_UnsupportedCopyWith<HoleError_Unsupported> get copyWith => _$HoleError_UnsupportedCopyWithImpl<HoleError_Unsupported>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HoleError_Unsupported&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'HoleError.unsupported(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HoleError_UnsupportedCopyWith<$Res> implements $HoleErrorCopyWith<$Res> {
+  factory $HoleError_UnsupportedCopyWith(HoleError_Unsupported value, $Res Function(HoleError_Unsupported) _then) = _$HoleError_UnsupportedCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HoleError_UnsupportedCopyWithImpl<$Res>
+    implements $HoleError_UnsupportedCopyWith<$Res> {
+  _$HoleError_UnsupportedCopyWithImpl(this._self, this._then);
+
+  final HoleError_Unsupported _self;
+  final $Res Function(HoleError_Unsupported) _then;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HoleError_Unsupported(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class HoleError_Cancelled extends HoleError {
+  const HoleError_Cancelled(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HoleError_CancelledCopyWith<HoleError_Cancelled> get copyWith => _$HoleError_CancelledCopyWithImpl<HoleError_Cancelled>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HoleError_Cancelled&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'HoleError.cancelled(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HoleError_CancelledCopyWith<$Res> implements $HoleErrorCopyWith<$Res> {
+  factory $HoleError_CancelledCopyWith(HoleError_Cancelled value, $Res Function(HoleError_Cancelled) _then) = _$HoleError_CancelledCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HoleError_CancelledCopyWithImpl<$Res>
+    implements $HoleError_CancelledCopyWith<$Res> {
+  _$HoleError_CancelledCopyWithImpl(this._self, this._then);
+
+  final HoleError_Cancelled _self;
+  final $Res Function(HoleError_Cancelled) _then;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HoleError_Cancelled(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class HoleError_AccessDenied extends HoleError {
+  const HoleError_AccessDenied(this.field0): super._();
+
+
+@override final  String field0;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$HoleError_AccessDeniedCopyWith<HoleError_AccessDenied> get copyWith => _$HoleError_AccessDeniedCopyWithImpl<HoleError_AccessDenied>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is HoleError_AccessDenied&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'HoleError.accessDenied(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $HoleError_AccessDeniedCopyWith<$Res> implements $HoleErrorCopyWith<$Res> {
+  factory $HoleError_AccessDeniedCopyWith(HoleError_AccessDenied value, $Res Function(HoleError_AccessDenied) _then) = _$HoleError_AccessDeniedCopyWithImpl;
+@override @useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$HoleError_AccessDeniedCopyWithImpl<$Res>
+    implements $HoleError_AccessDeniedCopyWith<$Res> {
+  _$HoleError_AccessDeniedCopyWithImpl(this._self, this._then);
+
+  final HoleError_AccessDenied _self;
+  final $Res Function(HoleError_AccessDenied) _then;
+
+/// Create a copy of HoleError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(HoleError_AccessDenied(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+// dart format on
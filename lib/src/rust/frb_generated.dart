@@ -64,7 +64,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -1823578080;
+  int get rustContentHash => 1342357207;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -75,16 +75,185 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  int crateApiHoleErrorCode({required HoleError that});
+
+  String crateApiHoleErrorMessage({required HoleError that});
+
+  Future<ReceiveOptions> crateApiReceiveOptionsDefault();
+
+  Future<SendOptions> crateApiSendOptionsDefault();
+
+  List<ShareHealth> crateApiCheckSharing();
+
+  Future<BigInt> crateApiClearTransfers({required String dataDir});
+
+  String crateApiCreateSendStream();
+
+  Future<void> crateApiDeleteTransfer({
+    required String dataDir,
+    required BigInt id,
+  });
+
+  void crateApiFinishSendStream({required String streamId});
+
+  Stream<String> crateApiInit({required String dataDir});
+
+  Future<TicketInfo> crateApiInspectTicket({
+    required String ticketStr,
+    required String dataDir,
+    String? password,
+  });
+
+  Future<List<ShareStatus>> crateApiListShares({required String dataDir});
+
+  Future<List<ContentTag>> crateApiListTags({
+    required String dataDir,
+    String? prefix,
+  });
+
+  Future<List<TransferRecord>> crateApiListTransfers({
+    required String dataDir,
+    TransferDirection? direction,
+    required int offset,
+    int? limit,
+  });
+
+  void crateApiPauseSharing();
+
+  Future<String> crateApiPinTag({
+    required String dataDir,
+    required String name,
+  });
+
+  Future<void> crateApiPushSendChunk({
+    required String streamId,
+    required Uint8List chunk,
+  });
+
+  Future<Uint8List> crateApiReadRemoteRange({
+    required String ticketStr,
+    required String dataDir,
+    int? index,
+    required BigInt offset,
+    required BigInt len,
+  });
+
+  Future<Uint8List> crateApiReceiveBytes({
+    required String ticketStr,
+    required String dataDir,
+    required ReceiveOptions options,
+    required RustStreamSink<String> sink,
+  });
+
   Stream<String> crateApiReceiveFile({
     required String ticketStr,
     required String dataDir,
     required String downloadDir,
   });
 
+  Stream<String> crateApiReceiveFileWithOptions({
+    required String ticketStr,
+    required String dataDir,
+    required String downloadDir,
+    required ReceiveOptions options,
+  });
+
+  Future<void> crateApiReceiveToStream({
+    required String ticketStr,
+    required String dataDir,
+    required ReceiveOptions options,
+    required RustStreamSink<Uint8List> chunks,
+    required RustStreamSink<String> sink,
+  });
+
+  Future<void> crateApiRemoveTag({
+    required String dataDir,
+    required String name,
+  });
+
+  Stream<String> crateApiReshare({
+    required String hash,
+    required String dataDir,
+  });
+
+  Stream<String> crateApiReshareWithOptions({
+    required String hash,
+    required String dataDir,
+    required SendOptions options,
+  });
+
+  void crateApiRespondToPeer({required String nodeId, required bool approve});
+
+  void crateApiResumeSharing();
+
   Stream<String> crateApiStartSend({
     required String filePath,
     required String dataDir,
   });
+
+  Stream<String> crateApiStartSendBytes({
+    required String name,
+    required Uint8List data,
+    required String dataDir,
+  });
+
+  Stream<String> crateApiStartSendBytesWithOptions({
+    required String name,
+    required Uint8List data,
+    required String dataDir,
+    required SendOptions options,
+  });
+
+  Stream<String> crateApiStartSendFiles({
+    required List<String> filePaths,
+    required String dataDir,
+  });
+
+  Stream<String> crateApiStartSendFilesWithOptions({
+    required List<String> filePaths,
+    required String dataDir,
+    required SendOptions options,
+  });
+
+  Stream<String> crateApiStartSendStream({
+    required String streamId,
+    required String name,
+    required String dataDir,
+  });
+
+  Stream<String> crateApiStartSendStreamWithOptions({
+    required String streamId,
+    required String name,
+    required String dataDir,
+    required SendOptions options,
+  });
+
+  Stream<String> crateApiStartSendText({
+    required String text,
+    required String dataDir,
+  });
+
+  Stream<String> crateApiStartSendTextWithOptions({
+    required String text,
+    required String dataDir,
+    required SendOptions options,
+  });
+
+  Stream<String> crateApiStartSendWithOptions({
+    required String filePath,
+    required String dataDir,
+    required SendOptions options,
+  });
+
+  Stream<String> crateApiStartStreamServer({
+    required String ticketStr,
+    required String dataDir,
+    int? index,
+  });
+
+  Future<void> crateApiStopShare({required String dataDir, required String id});
+
+  void crateApiStopStreamServer({required String url});
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -96,34 +265,274 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Stream<String> crateApiReceiveFile({
-    required String ticketStr,
+  int crateApiHoleErrorCode({required HoleError that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_hole_error(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiHoleErrorCodeConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHoleErrorCodeConstMeta => const TaskConstMeta(
+    debugName: "HoleError_code",
+    argNames: ["that"],
+  );
+
+  @override
+  String crateApiHoleErrorMessage({required HoleError that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_hole_error(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiHoleErrorMessageConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHoleErrorMessageConstMeta => const TaskConstMeta(
+    debugName: "HoleError_message",
+    argNames: ["that"],
+  );
+
+  @override
+  Future<ReceiveOptions> crateApiReceiveOptionsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 3,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_receive_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiReceiveOptionsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReceiveOptionsDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "ReceiveOptions_default",
+        argNames: [],
+      );
+
+  @override
+  Future<SendOptions> crateApiSendOptionsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 4,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_send_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSendOptionsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSendOptionsDefaultConstMeta => const TaskConstMeta(
+    debugName: "SendOptions_default",
+    argNames: [],
+  );
+
+  @override
+  List<ShareHealth> crateApiCheckSharing() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_share_health,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCheckSharingConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCheckSharingConstMeta => const TaskConstMeta(
+    debugName: "check_sharing",
+    argNames: [],
+  );
+
+  @override
+  Future<BigInt> crateApiClearTransfers({required String dataDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 6,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_hole_error,
+        ),
+        constMeta: kCrateApiClearTransfersConstMeta,
+        argValues: [dataDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiClearTransfersConstMeta => const TaskConstMeta(
+    debugName: "clear_transfers",
+    argNames: ["dataDir"],
+  );
+
+  @override
+  String crateApiCreateSendStream() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCreateSendStreamConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCreateSendStreamConstMeta => const TaskConstMeta(
+    debugName: "create_send_stream",
+    argNames: [],
+  );
+
+  @override
+  Future<void> crateApiDeleteTransfer({
     required String dataDir,
-    required String downloadDir,
+    required BigInt id,
   }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          sse_encode_u_64(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 8,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_hole_error,
+        ),
+        constMeta: kCrateApiDeleteTransferConstMeta,
+        argValues: [dataDir, id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDeleteTransferConstMeta => const TaskConstMeta(
+    debugName: "delete_transfer",
+    argNames: ["dataDir", "id"],
+  );
+
+  @override
+  void crateApiFinishSendStream({required String streamId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(streamId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_hole_error,
+        ),
+        constMeta: kCrateApiFinishSendStreamConstMeta,
+        argValues: [streamId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFinishSendStreamConstMeta => const TaskConstMeta(
+    debugName: "finish_send_stream",
+    argNames: ["streamId"],
+  );
+
+  @override
+  Stream<String> crateApiInit({required String dataDir}) {
     final sink = RustStreamSink<String>();
     unawaited(
       handler.executeNormal(
         NormalTask(
           callFfi: (port_) {
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_String(ticketStr, serializer);
             sse_encode_String(dataDir, serializer);
-            sse_encode_String(downloadDir, serializer);
             sse_encode_StreamSink_String_Sse(sink, serializer);
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 1,
+              funcId: 10,
               port: port_,
             );
           },
           codec: SseCodec(
             decodeSuccessData: sse_decode_unit,
-            decodeErrorData: sse_decode_AnyhowException,
+            decodeErrorData: sse_decode_hole_error,
           ),
-          constMeta: kCrateApiReceiveFileConstMeta,
-          argValues: [ticketStr, dataDir, downloadDir, sink],
+          constMeta: kCrateApiInitConstMeta,
+          argValues: [dataDir, sink],
           apiImpl: this,
         ),
       ),
@@ -131,113 +540,1877 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiReceiveFileConstMeta => const TaskConstMeta(
-    debugName: "receive_file",
-    argNames: ["ticketStr", "dataDir", "downloadDir", "sink"],
+  TaskConstMeta get kCrateApiInitConstMeta => const TaskConstMeta(
+    debugName: "init",
+    argNames: ["dataDir", "sink"],
   );
 
   @override
-  Stream<String> crateApiStartSend({
-    required String filePath,
+  Future<TicketInfo> crateApiInspectTicket({
+    required String ticketStr,
     required String dataDir,
+    String? password,
   }) {
-    final sink = RustStreamSink<String>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_String(filePath, serializer);
-            sse_encode_String(dataDir, serializer);
-            sse_encode_StreamSink_String_Sse(sink, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 2,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: sse_decode_AnyhowException,
-          ),
-          constMeta: kCrateApiStartSendConstMeta,
-          argValues: [filePath, dataDir, sink],
-          apiImpl: this,
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(ticketStr, serializer);
+          sse_encode_String(dataDir, serializer);
+          sse_encode_opt_String(password, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 11,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_ticket_info,
+          decodeErrorData: sse_decode_hole_error,
         ),
+        constMeta: kCrateApiInspectTicketConstMeta,
+        argValues: [ticketStr, dataDir, password],
+        apiImpl: this,
       ),
     );
-    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiStartSendConstMeta => const TaskConstMeta(
-    debugName: "start_send",
-    argNames: ["filePath", "dataDir", "sink"],
-  );
+  TaskConstMeta get kCrateApiInspectTicketConstMeta => const TaskConstMeta(
+    debugName: "inspect_ticket",
+    argNames: ["ticketStr", "dataDir", "password"],
+  );
+
+  @override
+  Future<List<ShareStatus>> crateApiListShares({required String dataDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 12,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_share_status,
+          decodeErrorData: sse_decode_hole_error,
+        ),
+        constMeta: kCrateApiListSharesConstMeta,
+        argValues: [dataDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiListSharesConstMeta => const TaskConstMeta(
+    debugName: "list_shares",
+    argNames: ["dataDir"],
+  );
+
+  @override
+  Future<List<ContentTag>> crateApiListTags({
+    required String dataDir,
+    String? prefix,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          sse_encode_opt_String(prefix, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 13,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_content_tag,
+          decodeErrorData: sse_decode_hole_error,
+        ),
+        constMeta: kCrateApiListTagsConstMeta,
+        argValues: [dataDir, prefix],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiListTagsConstMeta => const TaskConstMeta(
+    debugName: "list_tags",
+    argNames: ["dataDir", "prefix"],
+  );
+
+  @override
+  Future<List<TransferRecord>> crateApiListTransfers({
+    required String dataDir,
+    TransferDirection? direction,
+    required int offset,
+    int? limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          sse_encode_opt_box_autoadd_transfer_direction(direction, serializer);
+          sse_encode_u_32(offset, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_transfer_record,
+          decodeErrorData: sse_decode_hole_error,
+        ),
+        constMeta: kCrateApiListTransfersConstMeta,
+        argValues: [dataDir, direction, offset, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiListTransfersConstMeta => const TaskConstMeta(
+    debugName: "list_transfers",
+    argNames: ["dataDir", "direction", "offset", "limit"],
+  );
+
+  @override
+  void crateApiPauseSharing() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPauseSharingConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPauseSharingConstMeta => const TaskConstMeta(
+    debugName: "pause_sharing",
+    argNames: [],
+  );
+
+  @override
+  Future<String> crateApiPinTag({
+    required String dataDir,
+    required String name,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_hole_error,
+        ),
+        constMeta: kCrateApiPinTagConstMeta,
+        argValues: [dataDir, name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPinTagConstMeta => const TaskConstMeta(
+    debugName: "pin_tag",
+    argNames: ["dataDir", "name"],
+  );
+
+  @override
+  Future<void> crateApiPushSendChunk({
+    required String streamId,
+    required Uint8List chunk,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(streamId, serializer);
+          sse_encode_list_prim_u_8_strict(chunk, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_hole_error,
+        ),
+        constMeta: kCrateApiPushSendChunkConstMeta,
+        argValues: [streamId, chunk],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPushSendChunkConstMeta => const TaskConstMeta(
+    debugName: "push_send_chunk",
+    argNames: ["streamId", "chunk"],
+  );
+
+  @override
+  Future<Uint8List> crateApiReadRemoteRange({
+    required String ticketStr,
+    required String dataDir,
+    int? index,
+    required BigInt offset,
+    required BigInt len,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(ticketStr, serializer);
+          sse_encode_String(dataDir, serializer);
+          sse_encode_opt_box_autoadd_u_32(index, serializer);
+          sse_encode_u_64(offset, serializer);
+          sse_encode_u_64(len, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_hole_error,
+        ),
+        constMeta: kCrateApiReadRemoteRangeConstMeta,
+        argValues: [ticketStr, dataDir, index, offset, len],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReadRemoteRangeConstMeta => const TaskConstMeta(
+    debugName: "read_remote_range",
+    argNames: ["ticketStr", "dataDir", "index", "offset", "len"],
+  );
+
+  @override
+  Future<Uint8List> crateApiReceiveBytes({
+    required String ticketStr,
+    required String dataDir,
+    required ReceiveOptions options,
+    required RustStreamSink<String> sink,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(ticketStr, serializer);
+          sse_encode_String(dataDir, serializer);
+          sse_encode_box_autoadd_receive_options(options, serializer);
+          sse_encode_StreamSink_String_Sse(sink, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_hole_error,
+        ),
+        constMeta: kCrateApiReceiveBytesConstMeta,
+        argValues: [ticketStr, dataDir, options, sink],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReceiveBytesConstMeta => const TaskConstMeta(
+    debugName: "receive_bytes",
+    argNames: ["ticketStr", "dataDir", "options", "sink"],
+  );
+
+  @override
+  Stream<String> crateApiReceiveFile({
+    required String ticketStr,
+    required String dataDir,
+    required String downloadDir,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(ticketStr, serializer);
+            sse_encode_String(dataDir, serializer);
+            sse_encode_String(downloadDir, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 20,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_hole_error,
+          ),
+          constMeta: kCrateApiReceiveFileConstMeta,
+          argValues: [ticketStr, dataDir, downloadDir, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiReceiveFileConstMeta => const TaskConstMeta(
+    debugName: "receive_file",
+    argNames: ["ticketStr", "dataDir", "downloadDir", "sink"],
+  );
+
+  @override
+  Stream<String> crateApiReceiveFileWithOptions({
+    required String ticketStr,
+    required String dataDir,
+    required String downloadDir,
+    required ReceiveOptions options,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(ticketStr, serializer);
+            sse_encode_String(dataDir, serializer);
+            sse_encode_String(downloadDir, serializer);
+            sse_encode_box_autoadd_receive_options(options, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 21,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_hole_error,
+          ),
+          constMeta: kCrateApiReceiveFileWithOptionsConstMeta,
+          argValues: [ticketStr, dataDir, downloadDir, options, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiReceiveFileWithOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "receive_file_with_options",
+        argNames: ["ticketStr", "dataDir", "downloadDir", "options", "sink"],
+      );
+
+  @override
+  Future<void> crateApiReceiveToStream({
+    required String ticketStr,
+    required String dataDir,
+    required ReceiveOptions options,
+    required RustStreamSink<Uint8List> chunks,
+    required RustStreamSink<String> sink,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(ticketStr, serializer);
+          sse_encode_String(dataDir, serializer);
+          sse_encode_box_autoadd_receive_options(options, serializer);
+          sse_encode_StreamSink_list_prim_u_8_strict_Sse(chunks, serializer);
+          sse_encode_StreamSink_String_Sse(sink, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_hole_error,
+        ),
+        constMeta: kCrateApiReceiveToStreamConstMeta,
+        argValues: [ticketStr, dataDir, options, chunks, sink],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReceiveToStreamConstMeta => const TaskConstMeta(
+    debugName: "receive_to_stream",
+    argNames: ["ticketStr", "dataDir", "options", "chunks", "sink"],
+  );
+
+  @override
+  Future<void> crateApiRemoveTag({
+    required String dataDir,
+    required String name,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_hole_error,
+        ),
+        constMeta: kCrateApiRemoveTagConstMeta,
+        argValues: [dataDir, name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRemoveTagConstMeta => const TaskConstMeta(
+    debugName: "remove_tag",
+    argNames: ["dataDir", "name"],
+  );
+
+  @override
+  Stream<String> crateApiReshare({
+    required String hash,
+    required String dataDir,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(hash, serializer);
+            sse_encode_String(dataDir, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 24,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_hole_error,
+          ),
+          constMeta: kCrateApiReshareConstMeta,
+          argValues: [hash, dataDir, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiReshareConstMeta => const TaskConstMeta(
+    debugName: "reshare",
+    argNames: ["hash", "dataDir", "sink"],
+  );
+
+  @override
+  Stream<String> crateApiReshareWithOptions({
+    required String hash,
+    required String dataDir,
+    required SendOptions options,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(hash, serializer);
+            sse_encode_String(dataDir, serializer);
+            sse_encode_box_autoadd_send_options(options, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 25,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_hole_error,
+          ),
+          constMeta: kCrateApiReshareWithOptionsConstMeta,
+          argValues: [hash, dataDir, options, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiReshareWithOptionsConstMeta => const TaskConstMeta(
+    debugName: "reshare_with_options",
+    argNames: ["hash", "dataDir", "options", "sink"],
+  );
+
+  @override
+  void crateApiRespondToPeer({required String nodeId, required bool approve}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(nodeId, serializer);
+          sse_encode_bool(approve, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_hole_error,
+        ),
+        constMeta: kCrateApiRespondToPeerConstMeta,
+        argValues: [nodeId, approve],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRespondToPeerConstMeta => const TaskConstMeta(
+    debugName: "respond_to_peer",
+    argNames: ["nodeId", "approve"],
+  );
+
+  @override
+  void crateApiResumeSharing() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiResumeSharingConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiResumeSharingConstMeta => const TaskConstMeta(
+    debugName: "resume_sharing",
+    argNames: [],
+  );
+
+  @override
+  Stream<String> crateApiStartSend({
+    required String filePath,
+    required String dataDir,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(filePath, serializer);
+            sse_encode_String(dataDir, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 28,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_hole_error,
+          ),
+          constMeta: kCrateApiStartSendConstMeta,
+          argValues: [filePath, dataDir, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiStartSendConstMeta => const TaskConstMeta(
+    debugName: "start_send",
+    argNames: ["filePath", "dataDir", "sink"],
+  );
+
+  @override
+  Stream<String> crateApiStartSendBytes({
+    required String name,
+    required Uint8List data,
+    required String dataDir,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(name, serializer);
+            sse_encode_list_prim_u_8_strict(data, serializer);
+            sse_encode_String(dataDir, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 29,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_hole_error,
+          ),
+          constMeta: kCrateApiStartSendBytesConstMeta,
+          argValues: [name, data, dataDir, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiStartSendBytesConstMeta => const TaskConstMeta(
+    debugName: "start_send_bytes",
+    argNames: ["name", "data", "dataDir", "sink"],
+  );
+
+  @override
+  Stream<String> crateApiStartSendBytesWithOptions({
+    required String name,
+    required Uint8List data,
+    required String dataDir,
+    required SendOptions options,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(name, serializer);
+            sse_encode_list_prim_u_8_strict(data, serializer);
+            sse_encode_String(dataDir, serializer);
+            sse_encode_box_autoadd_send_options(options, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 30,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_hole_error,
+          ),
+          constMeta: kCrateApiStartSendBytesWithOptionsConstMeta,
+          argValues: [name, data, dataDir, options, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiStartSendBytesWithOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_send_bytes_with_options",
+        argNames: ["name", "data", "dataDir", "options", "sink"],
+      );
+
+  @override
+  Stream<String> crateApiStartSendFiles({
+    required List<String> filePaths,
+    required String dataDir,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_list_String(filePaths, serializer);
+            sse_encode_String(dataDir, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 31,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_hole_error,
+          ),
+          constMeta: kCrateApiStartSendFilesConstMeta,
+          argValues: [filePaths, dataDir, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiStartSendFilesConstMeta => const TaskConstMeta(
+    debugName: "start_send_files",
+    argNames: ["filePaths", "dataDir", "sink"],
+  );
+
+  @override
+  Stream<String> crateApiStartSendFilesWithOptions({
+    required List<String> filePaths,
+    required String dataDir,
+    required SendOptions options,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_list_String(filePaths, serializer);
+            sse_encode_String(dataDir, serializer);
+            sse_encode_box_autoadd_send_options(options, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 32,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_hole_error,
+          ),
+          constMeta: kCrateApiStartSendFilesWithOptionsConstMeta,
+          argValues: [filePaths, dataDir, options, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiStartSendFilesWithOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_send_files_with_options",
+        argNames: ["filePaths", "dataDir", "options", "sink"],
+      );
+
+  @override
+  Stream<String> crateApiStartSendStream({
+    required String streamId,
+    required String name,
+    required String dataDir,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(streamId, serializer);
+            sse_encode_String(name, serializer);
+            sse_encode_String(dataDir, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 33,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_hole_error,
+          ),
+          constMeta: kCrateApiStartSendStreamConstMeta,
+          argValues: [streamId, name, dataDir, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiStartSendStreamConstMeta => const TaskConstMeta(
+    debugName: "start_send_stream",
+    argNames: ["streamId", "name", "dataDir", "sink"],
+  );
+
+  @override
+  Stream<String> crateApiStartSendStreamWithOptions({
+    required String streamId,
+    required String name,
+    required String dataDir,
+    required SendOptions options,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(streamId, serializer);
+            sse_encode_String(name, serializer);
+            sse_encode_String(dataDir, serializer);
+            sse_encode_box_autoadd_send_options(options, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 34,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_hole_error,
+          ),
+          constMeta: kCrateApiStartSendStreamWithOptionsConstMeta,
+          argValues: [streamId, name, dataDir, options, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiStartSendStreamWithOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_send_stream_with_options",
+        argNames: ["streamId", "name", "dataDir", "options", "sink"],
+      );
+
+  @override
+  Stream<String> crateApiStartSendText({
+    required String text,
+    required String dataDir,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(text, serializer);
+            sse_encode_String(dataDir, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 35,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_hole_error,
+          ),
+          constMeta: kCrateApiStartSendTextConstMeta,
+          argValues: [text, dataDir, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiStartSendTextConstMeta => const TaskConstMeta(
+    debugName: "start_send_text",
+    argNames: ["text", "dataDir", "sink"],
+  );
+
+  @override
+  Stream<String> crateApiStartSendTextWithOptions({
+    required String text,
+    required String dataDir,
+    required SendOptions options,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(text, serializer);
+            sse_encode_String(dataDir, serializer);
+            sse_encode_box_autoadd_send_options(options, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 36,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_hole_error,
+          ),
+          constMeta: kCrateApiStartSendTextWithOptionsConstMeta,
+          argValues: [text, dataDir, options, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiStartSendTextWithOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_send_text_with_options",
+        argNames: ["text", "dataDir", "options", "sink"],
+      );
+
+  @override
+  Stream<String> crateApiStartSendWithOptions({
+    required String filePath,
+    required String dataDir,
+    required SendOptions options,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(filePath, serializer);
+            sse_encode_String(dataDir, serializer);
+            sse_encode_box_autoadd_send_options(options, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 37,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_hole_error,
+          ),
+          constMeta: kCrateApiStartSendWithOptionsConstMeta,
+          argValues: [filePath, dataDir, options, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiStartSendWithOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_send_with_options",
+        argNames: ["filePath", "dataDir", "options", "sink"],
+      );
+
+  @override
+  Stream<String> crateApiStartStreamServer({
+    required String ticketStr,
+    required String dataDir,
+    int? index,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(ticketStr, serializer);
+            sse_encode_String(dataDir, serializer);
+            sse_encode_opt_box_autoadd_u_32(index, serializer);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 38,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_hole_error,
+          ),
+          constMeta: kCrateApiStartStreamServerConstMeta,
+          argValues: [ticketStr, dataDir, index, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiStartStreamServerConstMeta => const TaskConstMeta(
+    debugName: "start_stream_server",
+    argNames: ["ticketStr", "dataDir", "index", "sink"],
+  );
+
+  @override
+  Future<void> crateApiStopShare({
+    required String dataDir,
+    required String id,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_hole_error,
+        ),
+        constMeta: kCrateApiStopShareConstMeta,
+        argValues: [dataDir, id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStopShareConstMeta => const TaskConstMeta(
+    debugName: "stop_share",
+    argNames: ["dataDir", "id"],
+  );
+
+  @override
+  void crateApiStopStreamServer({required String url}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_hole_error,
+        ),
+        constMeta: kCrateApiStopStreamServerConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStopStreamServerConstMeta => const TaskConstMeta(
+    debugName: "stop_stream_server",
+    argNames: ["url"],
+  );
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<Uint8List> dco_decode_StreamSink_list_prim_u_8_strict_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  HoleError dco_decode_box_autoadd_hole_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_hole_error(raw);
+  }
+
+  @protected
+  ReceiveOptions dco_decode_box_autoadd_receive_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_receive_options(raw);
+  }
+
+  @protected
+  SendOptions dco_decode_box_autoadd_send_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_send_options(raw);
+  }
+
+  @protected
+  TransferDirection dco_decode_box_autoadd_transfer_direction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_transfer_direction(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  ContentTag dco_decode_content_tag(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ContentTag(
+      name: dco_decode_String(arr[0]),
+      hash: dco_decode_String(arr[1]),
+      isShare: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  HoleError dco_decode_hole_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return HoleError_Internal(dco_decode_String(raw[1]));
+      case 1:
+        return HoleError_InvalidTicket(dco_decode_String(raw[1]));
+      case 2:
+        return HoleError_UnsupportedVersion(dco_decode_String(raw[1]));
+      case 3:
+        return HoleError_InvalidInput(dco_decode_String(raw[1]));
+      case 4:
+        return HoleError_PeerUnreachable(dco_decode_String(raw[1]));
+      case 5:
+        return HoleError_Timeout(dco_decode_String(raw[1]));
+      case 6:
+        return HoleError_HashMismatch(dco_decode_String(raw[1]));
+      case 7:
+        return HoleError_DiskFull(dco_decode_String(raw[1]));
+      case 8:
+        return HoleError_PermissionDenied(dco_decode_String(raw[1]));
+      case 9:
+        return HoleError_PasswordRequired(dco_decode_String(raw[1]));
+      case 10:
+        return HoleError_WrongPassword(dco_decode_String(raw[1]));
+      case 11:
+        return HoleError_MetadataInvalid(dco_decode_String(raw[1]));
+      case 12:
+        return HoleError_NotFound(dco_decode_String(raw[1]));
+      case 13:
+        return HoleError_TooLarge(dco_decode_String(raw[1]));
+      case 14:
+        return HoleError_Unsupported(dco_decode_String(raw[1]));
+      case 15:
+        return HoleError_Cancelled(dco_decode_String(raw[1]));
+      case 16:
+        return HoleError_AccessDenied(dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<ContentTag> dco_decode_list_content_tag(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_content_tag).toList();
+  }
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint32List;
+  }
+
+  @protected
+  Uint64List dco_decode_list_prim_u_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint64List;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<ShareHealth> dco_decode_list_share_health(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_share_health).toList();
+  }
+
+  @protected
+  List<ShareStatus> dco_decode_list_share_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_share_status).toList();
+  }
+
+  @protected
+  List<TransferRecord> dco_decode_list_transfer_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_transfer_record).toList();
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  TransferDirection? dco_decode_opt_box_autoadd_transfer_direction(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_transfer_direction(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  ReceiveOptions dco_decode_receive_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ReceiveOptions(
+      password: dco_decode_opt_String(arr[0]),
+      extraSources: dco_decode_list_String(arr[1]),
+      maxRetries: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      retryDelayMs: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      selectIndices: dco_decode_list_prim_u_32_strict(arr[4]),
+      selectPaths: dco_decode_list_String(arr[5]),
+    );
+  }
+
+  @protected
+  SendOptions dco_decode_send_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return SendOptions(
+      password: dco_decode_opt_String(arr[0]),
+      allowlist: dco_decode_list_String(arr[1]),
+      promptUnknownPeers: dco_decode_bool(arr[2]),
+      maxDownloads: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      expiresAfterSecs: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      persistent: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  ShareHealth dco_decode_share_health(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ShareHealth(
+      id: dco_decode_String(arr[0]),
+      paused: dco_decode_bool(arr[1]),
+      reachable: dco_decode_bool(arr[2]),
+      relayUrl: dco_decode_opt_String(arr[3]),
+      directAddrs: dco_decode_list_String(arr[4]),
+    );
+  }
+
+  @protected
+  ShareStatus dco_decode_share_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ShareStatus(
+      id: dco_decode_String(arr[0]),
+      ticket: dco_decode_opt_String(arr[1]),
+      filenames: dco_decode_list_String(arr[2]),
+      downloads: dco_decode_u_32(arr[3]),
+      maxDownloads: dco_decode_opt_box_autoadd_u_32(arr[4]),
+      expiresAtMs: dco_decode_opt_box_autoadd_u_64(arr[5]),
+      serving: dco_decode_bool(arr[6]),
+    );
+  }
+
+  @protected
+  TicketInfo dco_decode_ticket_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return TicketInfo(
+      sender: dco_decode_String(arr[0]),
+      version: dco_decode_u_32(arr[1]),
+      encrypted: dco_decode_bool(arr[2]),
+      isText: dco_decode_bool(arr[3]),
+      filenames: dco_decode_list_String(arr[4]),
+      mimeTypes: dco_decode_list_String(arr[5]),
+      totalSize: dco_decode_opt_box_autoadd_u_64(arr[6]),
+    );
+  }
+
+  @protected
+  TransferDirection dco_decode_transfer_direction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TransferDirection.values[raw as int];
+  }
+
+  @protected
+  TransferOutcome dco_decode_transfer_outcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TransferOutcome.values[raw as int];
+  }
+
+  @protected
+  TransferRecord dco_decode_transfer_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return TransferRecord(
+      id: dco_decode_u_64(arr[0]),
+      direction: dco_decode_transfer_direction(arr[1]),
+      peer: dco_decode_String(arr[2]),
+      hash: dco_decode_String(arr[3]),
+      filenames: dco_decode_list_String(arr[4]),
+      sizes: dco_decode_list_prim_u_64_strict(arr[5]),
+      fileHashes: dco_decode_list_String(arr[6]),
+      startedAtMs: dco_decode_u_64(arr[7]),
+      durationMs: dco_decode_u_64(arr[8]),
+      outcome: dco_decode_transfer_outcome(arr[9]),
+      error: dco_decode_opt_String(arr[10]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<Uint8List> sse_decode_StreamSink_list_prim_u_8_strict_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  HoleError sse_decode_box_autoadd_hole_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_hole_error(deserializer));
+  }
+
+  @protected
+  ReceiveOptions sse_decode_box_autoadd_receive_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_receive_options(deserializer));
+  }
+
+  @protected
+  SendOptions sse_decode_box_autoadd_send_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_send_options(deserializer));
+  }
+
+  @protected
+  TransferDirection sse_decode_box_autoadd_transfer_direction(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_transfer_direction(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  ContentTag sse_decode_content_tag(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_hash = sse_decode_String(deserializer);
+    var var_isShare = sse_decode_bool(deserializer);
+    return ContentTag(name: var_name, hash: var_hash, isShare: var_isShare);
+  }
+
+  @protected
+  HoleError sse_decode_hole_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_String(deserializer);
+        return HoleError_Internal(var_field0);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        return HoleError_InvalidTicket(var_field0);
+      case 2:
+        var var_field0 = sse_decode_String(deserializer);
+        return HoleError_UnsupportedVersion(var_field0);
+      case 3:
+        var var_field0 = sse_decode_String(deserializer);
+        return HoleError_InvalidInput(var_field0);
+      case 4:
+        var var_field0 = sse_decode_String(deserializer);
+        return HoleError_PeerUnreachable(var_field0);
+      case 5:
+        var var_field0 = sse_decode_String(deserializer);
+        return HoleError_Timeout(var_field0);
+      case 6:
+        var var_field0 = sse_decode_String(deserializer);
+        return HoleError_HashMismatch(var_field0);
+      case 7:
+        var var_field0 = sse_decode_String(deserializer);
+        return HoleError_DiskFull(var_field0);
+      case 8:
+        var var_field0 = sse_decode_String(deserializer);
+        return HoleError_PermissionDenied(var_field0);
+      case 9:
+        var var_field0 = sse_decode_String(deserializer);
+        return HoleError_PasswordRequired(var_field0);
+      case 10:
+        var var_field0 = sse_decode_String(deserializer);
+        return HoleError_WrongPassword(var_field0);
+      case 11:
+        var var_field0 = sse_decode_String(deserializer);
+        return HoleError_MetadataInvalid(var_field0);
+      case 12:
+        var var_field0 = sse_decode_String(deserializer);
+        return HoleError_NotFound(var_field0);
+      case 13:
+        var var_field0 = sse_decode_String(deserializer);
+        return HoleError_TooLarge(var_field0);
+      case 14:
+        var var_field0 = sse_decode_String(deserializer);
+        return HoleError_Unsupported(var_field0);
+      case 15:
+        var var_field0 = sse_decode_String(deserializer);
+        return HoleError_Cancelled(var_field0);
+      case 16:
+        var var_field0 = sse_decode_String(deserializer);
+        return HoleError_AccessDenied(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ContentTag> sse_decode_list_content_tag(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ContentTag>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_content_tag(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint64List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<ShareHealth> sse_decode_list_share_health(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ShareHealth>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_share_health(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ShareStatus> sse_decode_list_share_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ShareStatus>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_share_status(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TransferRecord> sse_decode_list_transfer_record(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TransferRecord>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_transfer_record(deserializer));
+    }
+    return ans_;
+  }
 
   @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+  TransferDirection? sse_decode_opt_box_autoadd_transfer_direction(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_transfer_direction(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  ReceiveOptions sse_decode_receive_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_password = sse_decode_opt_String(deserializer);
+    var var_extraSources = sse_decode_list_String(deserializer);
+    var var_maxRetries = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_retryDelayMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_selectIndices = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_selectPaths = sse_decode_list_String(deserializer);
+    return ReceiveOptions(
+      password: var_password,
+      extraSources: var_extraSources,
+      maxRetries: var_maxRetries,
+      retryDelayMs: var_retryDelayMs,
+      selectIndices: var_selectIndices,
+      selectPaths: var_selectPaths,
+    );
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  SendOptions sse_decode_send_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_password = sse_decode_opt_String(deserializer);
+    var var_allowlist = sse_decode_list_String(deserializer);
+    var var_promptUnknownPeers = sse_decode_bool(deserializer);
+    var var_maxDownloads = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_expiresAfterSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_persistent = sse_decode_bool(deserializer);
+    return SendOptions(
+      password: var_password,
+      allowlist: var_allowlist,
+      promptUnknownPeers: var_promptUnknownPeers,
+      maxDownloads: var_maxDownloads,
+      expiresAfterSecs: var_expiresAfterSecs,
+      persistent: var_persistent,
+    );
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  ShareHealth sse_decode_share_health(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    var var_id = sse_decode_String(deserializer);
+    var var_paused = sse_decode_bool(deserializer);
+    var var_reachable = sse_decode_bool(deserializer);
+    var var_relayUrl = sse_decode_opt_String(deserializer);
+    var var_directAddrs = sse_decode_list_String(deserializer);
+    return ShareHealth(
+      id: var_id,
+      paused: var_paused,
+      reachable: var_reachable,
+      relayUrl: var_relayUrl,
+      directAddrs: var_directAddrs,
+    );
   }
 
   @protected
-  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
+  ShareStatus sse_decode_share_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_ticket = sse_decode_opt_String(deserializer);
+    var var_filenames = sse_decode_list_String(deserializer);
+    var var_downloads = sse_decode_u_32(deserializer);
+    var var_maxDownloads = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_expiresAtMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_serving = sse_decode_bool(deserializer);
+    return ShareStatus(
+      id: var_id,
+      ticket: var_ticket,
+      filenames: var_filenames,
+      downloads: var_downloads,
+      maxDownloads: var_maxDownloads,
+      expiresAtMs: var_expiresAtMs,
+      serving: var_serving,
+    );
+  }
+
+  @protected
+  TicketInfo sse_decode_ticket_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sender = sse_decode_String(deserializer);
+    var var_version = sse_decode_u_32(deserializer);
+    var var_encrypted = sse_decode_bool(deserializer);
+    var var_isText = sse_decode_bool(deserializer);
+    var var_filenames = sse_decode_list_String(deserializer);
+    var var_mimeTypes = sse_decode_list_String(deserializer);
+    var var_totalSize = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return TicketInfo(
+      sender: var_sender,
+      version: var_version,
+      encrypted: var_encrypted,
+      isText: var_isText,
+      filenames: var_filenames,
+      mimeTypes: var_mimeTypes,
+      totalSize: var_totalSize,
+    );
+  }
+
+  @protected
+  TransferDirection sse_decode_transfer_direction(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    var inner = sse_decode_i_32(deserializer);
+    return TransferDirection.values[inner];
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  TransferOutcome sse_decode_transfer_outcome(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    var inner = sse_decode_i_32(deserializer);
+    return TransferOutcome.values[inner];
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  TransferRecord sse_decode_transfer_record(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    var var_id = sse_decode_u_64(deserializer);
+    var var_direction = sse_decode_transfer_direction(deserializer);
+    var var_peer = sse_decode_String(deserializer);
+    var var_hash = sse_decode_String(deserializer);
+    var var_filenames = sse_decode_list_String(deserializer);
+    var var_sizes = sse_decode_list_prim_u_64_strict(deserializer);
+    var var_fileHashes = sse_decode_list_String(deserializer);
+    var var_startedAtMs = sse_decode_u_64(deserializer);
+    var var_durationMs = sse_decode_u_64(deserializer);
+    var var_outcome = sse_decode_transfer_outcome(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return TransferRecord(
+      id: var_id,
+      direction: var_direction,
+      peer: var_peer,
+      hash: var_hash,
+      filenames: var_filenames,
+      sizes: var_sizes,
+      fileHashes: var_fileHashes,
+      startedAtMs: var_startedAtMs,
+      durationMs: var_durationMs,
+      outcome: var_outcome,
+      error: var_error,
+    );
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
@@ -257,12 +2430,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getInt32();
   }
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
   @protected
   void sse_encode_AnyhowException(
     AnyhowException self,
@@ -289,12 +2456,190 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_list_prim_u_8_strict_Sse(
+    RustStreamSink<Uint8List> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_hole_error(
+    HoleError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_hole_error(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_receive_options(
+    ReceiveOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_receive_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_send_options(
+    SendOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_send_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_transfer_direction(
+    TransferDirection self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_transfer_direction(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_content_tag(ContentTag self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.hash, serializer);
+    sse_encode_bool(self.isShare, serializer);
+  }
+
+  @protected
+  void sse_encode_hole_error(HoleError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case HoleError_Internal(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(field0, serializer);
+      case HoleError_InvalidTicket(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(field0, serializer);
+      case HoleError_UnsupportedVersion(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(field0, serializer);
+      case HoleError_InvalidInput(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(field0, serializer);
+      case HoleError_PeerUnreachable(field0: final field0):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(field0, serializer);
+      case HoleError_Timeout(field0: final field0):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(field0, serializer);
+      case HoleError_HashMismatch(field0: final field0):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(field0, serializer);
+      case HoleError_DiskFull(field0: final field0):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(field0, serializer);
+      case HoleError_PermissionDenied(field0: final field0):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(field0, serializer);
+      case HoleError_PasswordRequired(field0: final field0):
+        sse_encode_i_32(9, serializer);
+        sse_encode_String(field0, serializer);
+      case HoleError_WrongPassword(field0: final field0):
+        sse_encode_i_32(10, serializer);
+        sse_encode_String(field0, serializer);
+      case HoleError_MetadataInvalid(field0: final field0):
+        sse_encode_i_32(11, serializer);
+        sse_encode_String(field0, serializer);
+      case HoleError_NotFound(field0: final field0):
+        sse_encode_i_32(12, serializer);
+        sse_encode_String(field0, serializer);
+      case HoleError_TooLarge(field0: final field0):
+        sse_encode_i_32(13, serializer);
+        sse_encode_String(field0, serializer);
+      case HoleError_Unsupported(field0: final field0):
+        sse_encode_i_32(14, serializer);
+        sse_encode_String(field0, serializer);
+      case HoleError_Cancelled(field0: final field0):
+        sse_encode_i_32(15, serializer);
+        sse_encode_String(field0, serializer);
+      case HoleError_AccessDenied(field0: final field0):
+        sse_encode_i_32(16, serializer);
+        sse_encode_String(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_content_tag(
+    List<ContentTag> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_content_tag(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(self);
+  }
+
+  @protected
+  void sse_encode_list_prim_u_64_strict(
+    Uint64List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint64List(self);
+  }
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -305,6 +2650,189 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8List(self);
   }
 
+  @protected
+  void sse_encode_list_share_health(
+    List<ShareHealth> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_share_health(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_share_status(
+    List<ShareStatus> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_share_status(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_transfer_record(
+    List<TransferRecord> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_transfer_record(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_transfer_direction(
+    TransferDirection? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_transfer_direction(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_receive_options(
+    ReceiveOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.password, serializer);
+    sse_encode_list_String(self.extraSources, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxRetries, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.retryDelayMs, serializer);
+    sse_encode_list_prim_u_32_strict(self.selectIndices, serializer);
+    sse_encode_list_String(self.selectPaths, serializer);
+  }
+
+  @protected
+  void sse_encode_send_options(SendOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.password, serializer);
+    sse_encode_list_String(self.allowlist, serializer);
+    sse_encode_bool(self.promptUnknownPeers, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxDownloads, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.expiresAfterSecs, serializer);
+    sse_encode_bool(self.persistent, serializer);
+  }
+
+  @protected
+  void sse_encode_share_health(ShareHealth self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_bool(self.paused, serializer);
+    sse_encode_bool(self.reachable, serializer);
+    sse_encode_opt_String(self.relayUrl, serializer);
+    sse_encode_list_String(self.directAddrs, serializer);
+  }
+
+  @protected
+  void sse_encode_share_status(ShareStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_opt_String(self.ticket, serializer);
+    sse_encode_list_String(self.filenames, serializer);
+    sse_encode_u_32(self.downloads, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxDownloads, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.expiresAtMs, serializer);
+    sse_encode_bool(self.serving, serializer);
+  }
+
+  @protected
+  void sse_encode_ticket_info(TicketInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.sender, serializer);
+    sse_encode_u_32(self.version, serializer);
+    sse_encode_bool(self.encrypted, serializer);
+    sse_encode_bool(self.isText, serializer);
+    sse_encode_list_String(self.filenames, serializer);
+    sse_encode_list_String(self.mimeTypes, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.totalSize, serializer);
+  }
+
+  @protected
+  void sse_encode_transfer_direction(
+    TransferDirection self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_transfer_outcome(
+    TransferOutcome self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_transfer_record(
+    TransferRecord self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.id, serializer);
+    sse_encode_transfer_direction(self.direction, serializer);
+    sse_encode_String(self.peer, serializer);
+    sse_encode_String(self.hash, serializer);
+    sse_encode_list_String(self.filenames, serializer);
+    sse_encode_list_prim_u_64_strict(self.sizes, serializer);
+    sse_encode_list_String(self.fileHashes, serializer);
+    sse_encode_u_64(self.startedAtMs, serializer);
+    sse_encode_u_64(self.durationMs, serializer);
+    sse_encode_transfer_outcome(self.outcome, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
+  }
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -321,10 +2849,4 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putInt32(self);
   }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
 }
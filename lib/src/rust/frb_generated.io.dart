@@ -24,12 +24,107 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<Uint8List> dco_decode_StreamSink_list_prim_u_8_strict_Sse(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  HoleError dco_decode_box_autoadd_hole_error(dynamic raw);
+
+  @protected
+  ReceiveOptions dco_decode_box_autoadd_receive_options(dynamic raw);
+
+  @protected
+  SendOptions dco_decode_box_autoadd_send_options(dynamic raw);
+
+  @protected
+  TransferDirection dco_decode_box_autoadd_transfer_direction(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  ContentTag dco_decode_content_tag(dynamic raw);
+
+  @protected
+  HoleError dco_decode_hole_error(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<ContentTag> dco_decode_list_content_tag(dynamic raw);
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+  @protected
+  Uint64List dco_decode_list_prim_u_64_strict(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<ShareHealth> dco_decode_list_share_health(dynamic raw);
+
+  @protected
+  List<ShareStatus> dco_decode_list_share_status(dynamic raw);
+
+  @protected
+  List<TransferRecord> dco_decode_list_transfer_record(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  TransferDirection? dco_decode_opt_box_autoadd_transfer_direction(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  ReceiveOptions dco_decode_receive_options(dynamic raw);
+
+  @protected
+  SendOptions dco_decode_send_options(dynamic raw);
+
+  @protected
+  ShareHealth dco_decode_share_health(dynamic raw);
+
+  @protected
+  ShareStatus dco_decode_share_status(dynamic raw);
+
+  @protected
+  TicketInfo dco_decode_ticket_info(dynamic raw);
+
+  @protected
+  TransferDirection dco_decode_transfer_direction(dynamic raw);
+
+  @protected
+  TransferOutcome dco_decode_transfer_outcome(dynamic raw);
+
+  @protected
+  TransferRecord dco_decode_transfer_record(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
   @protected
   int dco_decode_u_8(dynamic raw);
 
@@ -44,12 +139,115 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<Uint8List> sse_decode_StreamSink_list_prim_u_8_strict_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  HoleError sse_decode_box_autoadd_hole_error(SseDeserializer deserializer);
+
+  @protected
+  ReceiveOptions sse_decode_box_autoadd_receive_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SendOptions sse_decode_box_autoadd_send_options(SseDeserializer deserializer);
+
+  @protected
+  TransferDirection sse_decode_box_autoadd_transfer_direction(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  ContentTag sse_decode_content_tag(SseDeserializer deserializer);
+
+  @protected
+  HoleError sse_decode_hole_error(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<ContentTag> sse_decode_list_content_tag(SseDeserializer deserializer);
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<ShareHealth> sse_decode_list_share_health(SseDeserializer deserializer);
+
+  @protected
+  List<ShareStatus> sse_decode_list_share_status(SseDeserializer deserializer);
+
+  @protected
+  List<TransferRecord> sse_decode_list_transfer_record(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  TransferDirection? sse_decode_opt_box_autoadd_transfer_direction(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  ReceiveOptions sse_decode_receive_options(SseDeserializer deserializer);
+
+  @protected
+  SendOptions sse_decode_send_options(SseDeserializer deserializer);
+
+  @protected
+  ShareHealth sse_decode_share_health(SseDeserializer deserializer);
+
+  @protected
+  ShareStatus sse_decode_share_status(SseDeserializer deserializer);
+
+  @protected
+  TicketInfo sse_decode_ticket_info(SseDeserializer deserializer);
+
+  @protected
+  TransferDirection sse_decode_transfer_direction(SseDeserializer deserializer);
+
+  @protected
+  TransferOutcome sse_decode_transfer_outcome(SseDeserializer deserializer);
+
+  @protected
+  TransferRecord sse_decode_transfer_record(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer);
 
@@ -59,9 +257,6 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
-
   @protected
   void sse_encode_AnyhowException(
     AnyhowException self,
@@ -74,15 +269,156 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_list_prim_u_8_strict_Sse(
+    RustStreamSink<Uint8List> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_hole_error(
+    HoleError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_receive_options(
+    ReceiveOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_send_options(
+    SendOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_transfer_direction(
+    TransferDirection self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_content_tag(ContentTag self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_hole_error(HoleError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_content_tag(
+    List<ContentTag> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_64_strict(
+    Uint64List self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_share_health(
+    List<ShareHealth> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_share_status(
+    List<ShareStatus> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_transfer_record(
+    List<TransferRecord> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_transfer_direction(
+    TransferDirection? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_receive_options(
+    ReceiveOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_send_options(SendOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_share_health(ShareHealth self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_share_status(ShareStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_ticket_info(TicketInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_transfer_direction(
+    TransferDirection self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_transfer_outcome(
+    TransferOutcome self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_transfer_record(
+    TransferRecord self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
@@ -91,9 +427,6 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
 }
 
 // Section: wire_class
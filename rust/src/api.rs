@@ -1,40 +1,778 @@
+use crate::crypto::{self, Password};
+use crate::download::{self, RetryPolicy};
+use crate::error;
+use crate::feed::{self, SinkWriter};
+use crate::filetype::{self, FileType};
+use crate::history;
 use crate::frb_generated::StreamSink;
+use crate::meta::{ContentKind, Item, ShareInfo};
+use crate::path::PathReporter;
+use crate::provider::{self, AccessPolicy, Provider, Share};
+use crate::shares::{self, Running, SavedShare};
+use crate::store;
+use crate::ranged::{self, RemoteFile};
+use crate::ticket::{self, ShareTicket, PROTOCOL_VERSION};
 use anyhow::{Context, Result};
 use futures::StreamExt;
-use iroh::{discovery::pkarr::PkarrPublisher, Endpoint, SecretKey};
+use serde::{Deserialize, Serialize};
+use iroh::discovery::{dns::DnsDiscovery, pkarr::PkarrPublisher, static_provider::StaticProvider};
+use iroh::{Endpoint, SecretKey};
 use iroh_blobs::api::blobs::{AddPathOptions, ImportMode};
 use iroh_blobs::{BlobFormat, hashseq::HashSeq};
-use iroh_blobs::protocol::GetRequest;
 use iroh_blobs::store::fs::FsStore;
 use iroh_blobs::ticket::BlobTicket;
-use iroh_blobs::BlobsProtocol;
-use std::path::PathBuf;
+use iroh_blobs::protocol::{ChunkRanges, ChunkRangesExt, GetRequest};
+use iroh_blobs::{BlobsProtocol, Hash, HashAndFormat};
+use std::collections::HashSet;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
 use std::str::FromStr;
-use tokio::io::AsyncReadExt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufWriter};
+use tokio::sync::{watch, Notify};
+use tokio_util::io::{ReaderStream, StreamReader};
 use bytes::Bytes;
 
+// Larger files have to be received into a file or a stream.
+const MAX_MEMORY_RECEIVE: u64 = 64 * 1024 * 1024;
+// Larger text is refused, it is handed to Dart as a single event.
+const MAX_TEXT_LEN: u64 = 4 * 1024 * 1024;
+// Size of the chunks handed to Dart by `receive_to_stream`.
+const STREAM_CHUNK_LEN: usize = 256 * 1024;
+
 fn get_secret() -> SecretKey {
     SecretKey::generate(&mut rand::rng())
 }
 
-pub async fn start_send(file_path: String, data_dir: String, sink: StreamSink<String>) -> Result<()> {
+#[derive(Debug, Clone, Default)]
+pub struct SendOptions {
+    /// Encrypt the file and its metadata with a key derived from this password.
+    pub password: Option<String>,
+    /// Only serve to these NodeIds. Empty means everyone, unless prompting.
+    pub allowlist: Vec<String>,
+    /// Emit `PEER_REQUEST:<node_id>` for peers not on the allowlist and wait
+    /// for `respond_to_peer` instead of denying them outright.
+    pub prompt_unknown_peers: bool,
+    /// Close the share after this many completed downloads, e.g. 1 for one-time tickets.
+    pub max_downloads: Option<u32>,
+    /// Close the share after this many seconds.
+    pub expires_after_secs: Option<u64>,
+    /// Save the share, so `init` serves it again under the same ticket after
+    /// the app restarts, until it expires, reaches its limit or is stopped
+    /// with `stop_share`.
+    pub persistent: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ReceiveOptions {
+    /// Password for shares that were sent encrypted.
+    pub password: Option<String>,
+    /// More providers of the same content, as tickets or NodeIds. With any
+    /// of these the download is spread over all providers in parallel.
+    pub extra_sources: Vec<String>,
+    /// How often to re-dial and resume after a failed connection or transfer. Defaults to 5.
+    pub max_retries: Option<u32>,
+    /// Delay before the first retry, doubled on every further one. Defaults to 1000.
+    pub retry_delay_ms: Option<u64>,
+    /// Only download these files of a share, by position. Together with
+    /// `select_paths`; both empty means everything.
+    pub select_indices: Vec<u32>,
+    /// Only download the files with these relative paths.
+    pub select_paths: Vec<String>,
+}
+
+/// Why a call failed. Calls with a sink also emit `ERROR_CODE:<code>` and
+/// `Error: <message>` there. Codes never change, so the app can localize
+/// them and pick a recovery, the message is English and for logs.
+#[derive(Debug, Clone)]
+pub enum HoleError {
+    /// Anything not covered below.
+    Internal(String),
+    InvalidTicket(String),
+    /// The ticket is from a newer version of the app, which needs an update.
+    UnsupportedVersion(String),
+    /// An argument like a NodeId, hash or file selection is invalid.
+    InvalidInput(String),
+    /// The sender can't be reached, or the connection broke.
+    PeerUnreachable(String),
+    Timeout(String),
+    /// Received data doesn't match its hash, it was corrupted on the way.
+    HashMismatch(String),
+    DiskFull(String),
+    PermissionDenied(String),
+    PasswordRequired(String),
+    WrongPassword(String),
+    /// The sender's metadata doesn't make sense.
+    MetadataInvalid(String),
+    /// A file, share, tag, stream or the like doesn't exist.
+    NotFound(String),
+    TooLarge(String),
+    /// Valid, but not possible with this content, e.g. streaming an encrypted share.
+    Unsupported(String),
+    /// The app closed a stream the call was using.
+    Cancelled(String),
+    /// The sender refused, e.g. the receiver isn't on its allowlist or a
+    /// one-time share was used up.
+    AccessDenied(String),
+}
+
+impl HoleError {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::Internal(_) => 0,
+            Self::InvalidTicket(_) => 1,
+            Self::UnsupportedVersion(_) => 2,
+            Self::InvalidInput(_) => 3,
+            Self::PeerUnreachable(_) => 4,
+            Self::Timeout(_) => 5,
+            Self::HashMismatch(_) => 6,
+            Self::DiskFull(_) => 7,
+            Self::PermissionDenied(_) => 8,
+            Self::PasswordRequired(_) => 9,
+            Self::WrongPassword(_) => 10,
+            Self::MetadataInvalid(_) => 11,
+            Self::NotFound(_) => 12,
+            Self::TooLarge(_) => 13,
+            Self::Unsupported(_) => 14,
+            Self::Cancelled(_) => 15,
+            Self::AccessDenied(_) => 16,
+        }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn message(&self) -> String {
+        match self {
+            Self::Internal(message)
+            | Self::InvalidTicket(message)
+            | Self::UnsupportedVersion(message)
+            | Self::InvalidInput(message)
+            | Self::PeerUnreachable(message)
+            | Self::Timeout(message)
+            | Self::HashMismatch(message)
+            | Self::DiskFull(message)
+            | Self::PermissionDenied(message)
+            | Self::PasswordRequired(message)
+            | Self::WrongPassword(message)
+            | Self::MetadataInvalid(message)
+            | Self::NotFound(message)
+            | Self::TooLarge(message)
+            | Self::Unsupported(message)
+            | Self::Cancelled(message)
+            | Self::AccessDenied(message) => message.clone(),
+        }
+    }
+}
+
+pub async fn start_send(file_path: String, data_dir: String, sink: StreamSink<String>) -> Result<(), HoleError> {
+    start_send_with_options(file_path, data_dir, SendOptions::default(), sink).await
+}
+
+pub async fn start_send_with_options(file_path: String, data_dir: String, options: SendOptions, sink: StreamSink<String>) -> Result<(), HoleError> {
     let path = PathBuf::from(&file_path);
     if !path.exists() {
-        sink.add("Error: File does not exist".to_string()).ok();
-        return Ok(())
+        return Err(error::report(&sink, HoleError::NotFound("File does not exist".into())));
+    }
+    send(Content::File(path), data_dir, options, sink).await
+}
+
+/// Shares a string like a link or a note. Receivers get it back as a
+/// `TEXT:<text>` event instead of a file.
+pub async fn start_send_text(text: String, data_dir: String, sink: StreamSink<String>) -> Result<(), HoleError> {
+    start_send_text_with_options(text, data_dir, SendOptions::default(), sink).await
+}
+
+pub async fn start_send_text_with_options(text: String, data_dir: String, options: SendOptions, sink: StreamSink<String>) -> Result<(), HoleError> {
+    send(Content::Text(text), data_dir, options, sink).await
+}
+
+/// Shares bytes handed over from Dart, e.g. from a share intent, without
+/// writing them to a file first.
+pub async fn start_send_bytes(name: String, data: Vec<u8>, data_dir: String, sink: StreamSink<String>) -> Result<(), HoleError> {
+    start_send_bytes_with_options(name, data, data_dir, SendOptions::default(), sink).await
+}
+
+pub async fn start_send_bytes_with_options(name: String, data: Vec<u8>, data_dir: String, options: SendOptions, sink: StreamSink<String>) -> Result<(), HoleError> {
+    send(Content::Bytes { name, data }, data_dir, options, sink).await
+}
+
+/// Opens a stream for `start_send_stream`, returning its id. Feed it with
+/// `push_send_chunk` and end it with `finish_send_stream`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn create_send_stream() -> String {
+    feed::create()
+}
+
+pub async fn push_send_chunk(stream_id: String, chunk: Vec<u8>) -> Result<(), HoleError> {
+    Ok(feed::push(&stream_id, chunk).await?)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn finish_send_stream(stream_id: String) -> Result<(), HoleError> {
+    Ok(feed::finish(&stream_id)?)
+}
+
+/// Shares the content of a stream from `create_send_stream`, imported into
+/// the store while it is being pushed. The ticket comes once it's finished.
+pub async fn start_send_stream(stream_id: String, name: String, data_dir: String, sink: StreamSink<String>) -> Result<(), HoleError> {
+    start_send_stream_with_options(stream_id, name, data_dir, SendOptions::default(), sink).await
+}
+
+pub async fn start_send_stream_with_options(stream_id: String, name: String, data_dir: String, options: SendOptions, sink: StreamSink<String>) -> Result<(), HoleError> {
+    send(Content::Stream { name, stream_id }, data_dir, options, sink).await
+}
+
+/// Shares several files at once. Receivers can pick which of them to
+/// download, see `ReceiveOptions::select_indices`.
+pub async fn start_send_files(file_paths: Vec<String>, data_dir: String, sink: StreamSink<String>) -> Result<(), HoleError> {
+    start_send_files_with_options(file_paths, data_dir, SendOptions::default(), sink).await
+}
+
+pub async fn start_send_files_with_options(file_paths: Vec<String>, data_dir: String, options: SendOptions, sink: StreamSink<String>) -> Result<(), HoleError> {
+    let paths: Vec<_> = file_paths.iter().map(PathBuf::from).collect();
+    if paths.is_empty() || paths.iter().any(|path| !path.is_file()) {
+        return Err(error::report(&sink, HoleError::NotFound("File does not exist".into())));
+    }
+    send(Content::Files(paths), data_dir, options, sink).await
+}
+
+pub async fn reshare(hash: String, data_dir: String, sink: StreamSink<String>) -> Result<(), HoleError> {
+    reshare_with_options(hash, data_dir, SendOptions::default(), sink).await
+}
+
+/// Shares received content on, straight from the store and with its original
+/// metadata, so nothing is imported again. `hash` is the hash of the share as
+/// in `TransferRecord::hash`. The password option is ignored, an encrypted
+/// share keeps the password it was sent with.
+pub async fn reshare_with_options(hash: String, data_dir: String, options: SendOptions, sink: StreamSink<String>) -> Result<(), HoleError> {
+    share_on(hash, data_dir, options, sink.clone()).await.map_err(|e| error::report(&sink, e))
+}
+
+async fn share_on(hash: String, data_dir: String, options: SendOptions, sink: StreamSink<String>) -> Result<()> {
+    let policy = AccessPolicy::new(&options.allowlist, options.prompt_unknown_peers)?;
+    let hash = Hash::from_str(hash.trim()).map_err(|_| HoleError::InvalidInput("Invalid hash".into()))?;
+
+    let store = store::open(&data_dir).await?;
+
+    let (root, version, hashes, files, mut record) = received_share(&store, hash).await?;
+    record.file_hashes = files.iter().map(|hash| hash.to_string()).collect();
+    let name = record.filenames.first().cloned().unwrap_or_else(|| hash.to_string());
+    store::tag_sent(&store, &name, root).await?;
+    let saved = new_share(root, version, hashes.into_iter().collect(), files, record, &options);
+    if options.persistent {
+        shares::save(Path::new(&data_dir), saved.clone()).await?;
     }
+    serve(&store, Path::new(&data_dir), saved, options.persistent, policy, sink).await
+}
+
+// Finds out what a received share consists of: its root, layout version, every
+// blob of it and its files, with a record naming them.
+async fn received_share(store: &FsStore, hash: Hash) -> Result<(HashAndFormat, u32, HashSet<Hash>, Vec<Hash>, TransferRecord)> {
+    if !store.remote().local(HashAndFormat::raw(hash)).await?.is_complete() {
+        return Err(HoleError::NotFound("Not found in the store".into()).into());
+    }
+    let mut record = TransferRecord::new(TransferDirection::Send, hash);
+
+    // A share is a sequence whose metadata is here, anything else a raw blob
+    let seq = HashSeq::try_from(store.get_bytes(hash).await?).ok().filter(|seq| seq.len() >= 2);
+    let meta_hash = seq.as_ref().and_then(|seq| seq.get(0));
+    let is_share = match meta_hash {
+        Some(meta_hash) => store.remote().local(HashAndFormat::raw(meta_hash)).await?.is_complete(),
+        None => false,
+    };
+    let Some(seq) = seq.filter(|_| is_share) else {
+        let size = store.observe(hash).await?.validated_size().unwrap_or_default();
+        record.sizes = vec![size];
+        return Ok((HashAndFormat::raw(hash), 0, [hash].into(), vec![hash], record));
+    };
+    if !store.remote().local(HashAndFormat::hash_seq(hash)).await?.is_complete() {
+        return Err(HoleError::NotFound("Only completely received shares can be shared on".into()).into());
+    }
+    let hashes: Vec<_> = seq.into_iter().collect();
+
+    // Names are only known for unencrypted shares
+    let meta_bytes = store.get_bytes(hashes[0]).await?;
+    let encrypted = crypto::is_encrypted(&meta_bytes);
+    let files: Vec<_> = if encrypted {
+        [1].into_iter().chain(3..hashes.len()).map(|i| hashes[i]).collect()
+    } else {
+        let info = match hashes.get(2) {
+            Some(info_hash) => read_info(store, *info_hash, None).await?,
+            None => ShareInfo::default(),
+        };
+        let items = share_items(&String::from_utf8_lossy(&meta_bytes), &info);
+        if items.iter().any(|item| hashes.get(item.child as usize).is_none()) {
+            return Err(HoleError::MetadataInvalid("Metadata invalid".into()).into());
+        }
+        record.filenames = items.iter().map(|item| item.name.clone()).collect();
+        record.sizes = items.iter().map(|item| item.size.unwrap_or_default()).collect();
+        items.iter().map(|item| hashes[item.child as usize]).collect()
+    };
+    let version = ticket::version_for(encrypted, files.len());
+    let all = hashes.into_iter().chain([hash]).collect();
+    Ok((HashAndFormat::hash_seq(hash), version, all, files, record))
+}
 
+// What a share carries
+enum Content {
+    File(PathBuf),
+    Files(Vec<PathBuf>),
+    Text(String),
+    Bytes { name: String, data: Vec<u8> },
+    Stream { name: String, stream_id: String },
+}
+
+async fn send(content: Content, data_dir: String, options: SendOptions, sink: StreamSink<String>) -> Result<(), HoleError> {
+    share(content, data_dir, options, sink.clone()).await.map_err(|e| error::report(&sink, e))
+}
+
+async fn share(content: Content, data_dir: String, options: SendOptions, sink: StreamSink<String>) -> Result<()> {
     sink.add("[V17-FORCE-REBUILD] Initializing...".to_string()).ok();
 
-    let data_path = PathBuf::from(&data_dir).join("sendme_store_send");
-    tokio::fs::create_dir_all(&data_path).await?;
-    let store = FsStore::load(&data_path).await?;
+    let policy = AccessPolicy::new(&options.allowlist, options.prompt_unknown_peers)?;
+
+    let store = store::open(&data_dir).await?;
+
+    // One key for every blob of the share
+    let password = options.password.as_deref().filter(|p| !p.is_empty()).map(Password::new);
+
+    // 1. Import File(s) or Text
+    let (file_hashes, filename, info) = match content {
+        Content::File(path) => {
+            let file_hash = import_file(&store, &path, password.as_ref(), &sink).await?;
+            let filename = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let info = ShareInfo {
+                mime: Some(filetype::detect_file(&path).await.mime.to_string()),
+                kind: ContentKind::File,
+                size: Some(tokio::fs::metadata(&path).await?.len()),
+                items: Vec::new(),
+                version: Some(PROTOCOL_VERSION),
+            };
+            (vec![file_hash], filename, info)
+        }
+        Content::Files(paths) => {
+            let mut file_hashes = Vec::new();
+            let mut items = Vec::new();
+            let names = item_names(&paths)?;
+            for (i, (path, name)) in paths.iter().zip(names).enumerate() {
+                file_hashes.push(import_file(&store, path, password.as_ref(), &sink).await?);
+                items.push(Item {
+                    name,
+                    child: if i == 0 { 1 } else { i as u64 + 2 },
+                    mime: Some(filetype::detect_file(path).await.mime.to_string()),
+                    size: Some(tokio::fs::metadata(path).await?.len()),
+                });
+            }
+            // Receivers without items support get the first file under its own name
+            let filename = items[0].name.clone();
+            let info = ShareInfo {
+                mime: None,
+                kind: ContentKind::File,
+                size: Some(items.iter().filter_map(|item| item.size).sum()),
+                items,
+                version: Some(PROTOCOL_VERSION),
+            };
+            (file_hashes, filename, info)
+        }
+        Content::Text(text) => {
+            sink.add("Importing...".to_string()).ok();
+            let size = text.len() as u64;
+            let bytes = match &password {
+                Some(password) => crypto::encrypt_bytes(password, text.as_bytes()).await?,
+                None => text.into_bytes(),
+            };
+            let file_hash = import_bytes(&store, bytes).await.context("Import failed")?;
+            // Receivers that don't know about text save it under this name
+            let info = ShareInfo {
+                mime: Some("text/plain".to_string()),
+                kind: ContentKind::Text,
+                size: Some(size),
+                items: Vec::new(),
+                version: Some(PROTOCOL_VERSION),
+            };
+            (vec![file_hash], "text.txt".to_string(), info)
+        }
+        Content::Bytes { name, data } => {
+            let file_type = filetype::detect_named(&name, &data[..data.len().min(filetype::HEADER_LEN)]);
+            let size = data.len() as u64;
+            let chunks = futures::stream::once(async move { Ok(Bytes::from(data)) });
+            let file_hash = import_stream(&store, chunks, password.as_ref(), &sink).await?;
+            let info = ShareInfo {
+                mime: Some(file_type.mime.to_string()),
+                kind: ContentKind::File,
+                size: Some(size),
+                items: Vec::new(),
+                version: Some(PROTOCOL_VERSION),
+            };
+            (vec![file_hash], name, info)
+        }
+        Content::Stream { name, stream_id } => {
+            let chunks = feed::take(&stream_id)?;
+            // Keep the start of the content to detect its type, and count its size
+            let header = Arc::new(Mutex::new((Vec::new(), 0u64)));
+            let tap = header.clone();
+            let chunks = chunks.inspect(move |chunk| {
+                let (header, size) = &mut *tap.lock().unwrap();
+                if let Ok(chunk) = chunk {
+                    let n = (filetype::HEADER_LEN - header.len()).min(chunk.len());
+                    header.extend_from_slice(&chunk[..n]);
+                    *size += chunk.len() as u64;
+                }
+            });
+            let file_hash = import_stream(&store, chunks, password.as_ref(), &sink).await?;
+            let (header, size) = &*header.lock().unwrap();
+            let file_type = filetype::detect_named(&name, header);
+            let info = ShareInfo {
+                mime: Some(file_type.mime.to_string()),
+                kind: ContentKind::File,
+                size: Some(*size),
+                items: Vec::new(),
+                version: Some(PROTOCOL_VERSION),
+            };
+            (vec![file_hash], name, info)
+        }
+    };
+
+    let items = share_items(&filename, &info);
+
+    // 2. Import Metadata (Filename, then MIME type and the rest as JSON)
+    let (meta_bytes, info_bytes) = match &password {
+        Some(password) => (
+            crypto::encrypt_bytes(password, filename.as_bytes()).await?,
+            crypto::encrypt_bytes(password, &info.to_bytes()).await?,
+        ),
+        None => (filename.into_bytes(), info.to_bytes()),
+    };
+    let meta_hash = import_bytes(&store, meta_bytes).await.context("Meta import failed")?;
+    let info_hash = import_bytes(&store, info_bytes).await.context("Info import failed")?;
+
+    // 3. Create HashSeq [meta, file, info, more files...]
+    let seq = HashSeq::from_iter(
+        [meta_hash, file_hashes[0], info_hash].into_iter().chain(file_hashes[1..].iter().copied()),
+    );
+    let seq_hash = import_bytes(&store, Bytes::from(seq).to_vec()).await.context("Seq import failed")?;
+    let root = HashAndFormat { hash: seq_hash, format: BlobFormat::HashSeq };
+    store::tag_sent(&store, &items[0].name, root).await?;
+
+    // Every download is recorded with these files
+    let mut record = TransferRecord::new(TransferDirection::Send, seq_hash);
+    record.filenames = items.iter().map(|item| item.name.clone()).collect();
+    record.sizes = items.iter().map(|item| item.size.unwrap_or_default()).collect();
+    record.file_hashes = file_hashes.iter().map(|hash| hash.to_string()).collect();
+
+    let hashes = [seq_hash, meta_hash, info_hash].into_iter().chain(file_hashes.iter().copied()).collect();
+    let version = ticket::version_for(password.is_some(), file_hashes.len());
+    let saved = new_share(root, version, hashes, file_hashes, record, &options);
+    if options.persistent {
+        shares::save(Path::new(&data_dir), saved.clone()).await?;
+    }
+    serve(&store, Path::new(&data_dir), saved, options.persistent, policy, sink).await
+}
+
+fn new_share(root: HashAndFormat, version: u32, hashes: Vec<Hash>, files: Vec<Hash>, record: TransferRecord, options: &SendOptions) -> SavedShare {
+    SavedShare {
+        id: feed::create_id(),
+        root,
+        version,
+        secret_key: hex::encode(get_secret().to_bytes()),
+        hashes,
+        files,
+        record,
+        allowlist: options.allowlist.clone(),
+        prompt_unknown_peers: options.prompt_unknown_peers,
+        max_downloads: options.max_downloads.filter(|max| *max > 0),
+        downloads: 0,
+        expires_at_ms: options.expires_after_secs.map(|secs| history::now_ms() + secs * 1000),
+        ticket: None,
+    }
+}
+
+// Serves a share from the store until it expires, reaches its download limit
+// or is stopped. Saved shares are forgotten once they are over.
+async fn serve(store: &FsStore, data_dir: &Path, saved: SavedShare, persistent: bool, policy: AccessPolicy, sink: StreamSink<String>) -> Result<()> {
+    let Some(running) = shares::register(&saved.id) else {
+        // Already served, e.g. by an earlier `init`
+        return Ok(());
+    };
+    let res = serve_until_done(store, data_dir, &saved, persistent, policy, running, &sink).await;
+    shares::unregister(&saved.id);
+    res
+}
+
+async fn serve_until_done(store: &FsStore, data_dir: &Path, saved: &SavedShare, persistent: bool, policy: AccessPolicy, running: Arc<Running>, sink: &StreamSink<String>) -> Result<()> {
+    let key: [u8; 32] = hex::decode(&saved.secret_key)?.try_into().map_err(|_| anyhow::anyhow!("Invalid secret key"))?;
+    let secret_key = SecretKey::from_bytes(&key);
+
+    let limit_reached = Arc::new(Notify::new());
+    let share = Share {
+        root: saved.root,
+        hashes: saved.hashes.iter().copied().collect(),
+        files: saved.files.iter().copied().collect(),
+        max_downloads: saved.max_downloads,
+        limit_reached: limit_reached.clone(),
+        data_dir: data_dir.to_path_buf(),
+        record: saved.record.clone(),
+        downloaded: saved.downloads,
+        saved_id: persistent.then(|| saved.id.clone()),
+    };
+    let provider = Provider::new(policy, share);
+    sink.add(format!("DEBUG: Generated Ticket Version: {}", saved.version)).ok();
+    sink.add("SHARE_ID:".to_string() + &saved.id).ok();
+
+    let expiry = async {
+        match saved.expires_at_ms {
+            Some(at) => tokio::time::sleep(Duration::from_millis(at.saturating_sub(history::now_ms()))).await,
+            None => futures::future::pending::<()>().await,
+        }
+    };
+    tokio::pin!(expiry);
+    let mut paused = shares::paused();
+    let mut resumed = false;
+    let over = loop {
+        // Nothing is bound while paused
+        tokio::select! {
+            _ = paused.wait_for(|paused| !*paused) => {}
+            _ = &mut expiry => {
+                sink.add("SHARE_EXPIRED".to_string()).ok();
+                break true;
+            }
+            _ = running.stop.notified() => {
+                sink.add("SHARE_STOPPED".to_string()).ok();
+                break false;
+            }
+        }
+
+        // Same key, same NodeId, so the ticket stays valid
+        let endpoint = Endpoint::builder()
+            .secret_key(secret_key.clone())
+            .discovery(PkarrPublisher::n0_dns())
+            .alpns(vec![iroh_blobs::protocol::ALPN.to_vec()])
+            .bind().await?;
+        let events = provider.spawn_event_handler(endpoint.clone(), sink.clone());
+        let blobs = BlobsProtocol::new(store, Some(events));
+        let router = iroh::protocol::Router::builder(endpoint)
+            .accept(iroh_blobs::ALPN, blobs.clone())
+            .spawn();
+
+        // Without a network this takes until there is one, which mustn't
+        // hold up a pause, a stop or the expiry
+        let ep = router.endpoint();
+        let cut_short = tokio::select! {
+            _ = ep.online() => None,
+            over = interrupted(expiry.as_mut(), &limit_reached, &running, &mut paused, sink) => Some(over),
+        };
+        let over = match cut_short {
+            Some(over) => over,
+            None => {
+                *running.endpoint.lock().unwrap() = Some(ep.clone());
+
+                // Ticket now points to the HashSeq
+                let root = saved.root;
+                let ticket = ShareTicket::new(saved.version, BlobTicket::new(ep.addr(), root.hash, root.format)).to_string();
+                if persistent {
+                    let latest = ticket.clone();
+                    shares::update(data_dir, &saved.id, move |saved| saved.ticket = Some(latest)).await?;
+                }
+                if resumed {
+                    sink.add("SHARE_RESUMED".to_string()).ok();
+                }
+                sink.add("TICKET:".to_string() + &ticket).ok();
+                sink.add("Ready!".to_string()).ok();
+
+                interrupted(expiry.as_mut(), &limit_reached, &running, &mut paused, sink).await
+            }
+        };
+        running.endpoint.lock().unwrap().take();
+        router.shutdown().await?;
+        match over {
+            Some(over) => break over,
+            None => resumed = true,
+        }
+    };
+    if persistent && over {
+        shares::remove(data_dir, &saved.id).await?;
+    }
+    Ok(())
+}
+
+// Waits for what ends serving on the current endpoint: Some(true) once the
+// share is over, Some(false) once it is stopped and None once it is paused.
+async fn interrupted(
+    expiry: Pin<&mut impl Future<Output = ()>>,
+    limit_reached: &Notify,
+    running: &Running,
+    paused: &mut watch::Receiver<bool>,
+    sink: &StreamSink<String>,
+) -> Option<bool> {
+    tokio::select! {
+        _ = expiry => {
+            sink.add("SHARE_EXPIRED".to_string()).ok();
+            Some(true)
+        }
+        _ = limit_reached.notified() => {
+            sink.add("SHARE_CLOSED:Download limit reached".to_string()).ok();
+            Some(true)
+        }
+        _ = running.stop.notified() => {
+            sink.add("SHARE_STOPPED".to_string()).ok();
+            Some(false)
+        }
+        _ = paused.wait_for(|paused| *paused) => {
+            sink.add("SHARE_PAUSED".to_string()).ok();
+            None
+        }
+    }
+}
+
+/// Stops serving all shares until `resume_sharing`, e.g. when the app goes
+/// to the background. Endpoints are closed, shares and their tickets stay.
+#[flutter_rust_bridge::frb(sync)]
+pub fn pause_sharing() {
+    shares::set_paused(true);
+}
+
+/// Serves all shares again after `pause_sharing`, on new endpoints with the
+/// same NodeIds, so the tickets handed out keep working.
+#[flutter_rust_bridge::frb(sync)]
+pub fn resume_sharing() {
+    shares::set_paused(false);
+}
+
+#[derive(Debug, Clone)]
+pub struct ShareHealth {
+    pub id: String,
+    pub paused: bool,
+    /// Whether the endpoint is bound and reachable, through a relay or directly.
+    pub reachable: bool,
+    pub relay_url: Option<String>,
+    pub direct_addrs: Vec<String>,
+}
+
+/// Tells how every share being served is doing, e.g. to check whether
+/// `resume_sharing` is needed after the app returns to the foreground.
+#[flutter_rust_bridge::frb(sync)]
+pub fn check_sharing() -> Vec<ShareHealth> {
+    let paused = *shares::paused().borrow();
+    shares::running()
+        .into_iter()
+        .map(|(id, running)| {
+            let endpoint = running.endpoint.lock().unwrap().clone().filter(|ep| !ep.is_closed());
+            let addr = endpoint.map(|ep| ep.addr());
+            let relay_url = addr.as_ref().and_then(|addr| addr.relay_urls().next().map(|url| url.to_string()));
+            let direct_addrs: Vec<_> = addr.iter().flat_map(|addr| addr.ip_addrs()).map(|addr| addr.to_string()).collect();
+            ShareHealth {
+                id,
+                paused,
+                reachable: relay_url.is_some() || !direct_addrs.is_empty(),
+                relay_url,
+                direct_addrs,
+            }
+        })
+        .collect()
+}
+
+/// Serves the saved shares again, with the tickets they had. Call once the
+/// app starts, events of all shares go to `sink`, each share starting with
+/// `SHARE_RESTORED:<id>`. Returns once they are all started, before they
+/// are bound, each share's `Ready!` follows on `sink`. Content of older
+/// versions is merged in the background, `MIGRATION_SKIPPED:<hash>` follows
+/// for every blob that couldn't be, or with the name of a whole old store.
+pub async fn init(data_dir: String, sink: StreamSink<String>) -> Result<(), HoleError> {
+    restore(data_dir, sink.clone()).await.map_err(|e| error::report(&sink, e))
+}
+
+async fn restore(data_dir: String, sink: StreamSink<String>) -> Result<()> {
+    let store = store::open(&data_dir).await?;
+    // Serving doesn't wait for older stores to be merged
+    tokio::spawn({
+        let (data_dir, sink) = (data_dir.clone(), sink.clone());
+        async move {
+            for skipped in store::migrated(&data_dir).await {
+                sink.add("MIGRATION_SKIPPED:".to_string() + &skipped).ok();
+            }
+        }
+    });
+    for saved in shares::list(Path::new(&data_dir)).await? {
+        if saved.is_done() {
+            shares::remove(Path::new(&data_dir), &saved.id).await?;
+            continue;
+        }
+        if shares::is_running(&saved.id) {
+            continue;
+        }
+        let policy = match AccessPolicy::new(&saved.allowlist, saved.prompt_unknown_peers) {
+            Ok(policy) => policy,
+            Err(e) => {
+                error::report(&sink, e);
+                continue;
+            }
+        };
+        sink.add("SHARE_RESTORED:".to_string() + &saved.id).ok();
+        let (store, data_dir, sink) = (store.clone(), data_dir.clone(), sink.clone());
+        tokio::spawn(async move {
+            if let Err(e) = serve(&store, Path::new(&data_dir), saved, true, policy, sink.clone()).await {
+                error::report(&sink, e);
+            }
+        });
+    }
+    Ok(())
+}
+
+/// A saved share, see `SendOptions::persistent`.
+#[derive(Debug, Clone)]
+pub struct ShareStatus {
+    pub id: String,
+    /// The latest ticket. Earlier ones keep working, they have the same NodeId.
+    pub ticket: Option<String>,
+    pub filenames: Vec<String>,
+    pub downloads: u32,
+    pub max_downloads: Option<u32>,
+    /// Unix time in milliseconds.
+    pub expires_at_ms: Option<u64>,
+    /// Whether it is being served right now.
+    pub serving: bool,
+}
+
+pub async fn list_shares(data_dir: String) -> Result<Vec<ShareStatus>, HoleError> {
+    let shares = shares::list(Path::new(&data_dir)).await?;
+    Ok(shares
+        .into_iter()
+        .filter(|saved| !saved.is_done())
+        .map(|saved| ShareStatus {
+            serving: shares::is_running(&saved.id),
+            id: saved.id,
+            ticket: saved.ticket,
+            filenames: saved.record.filenames,
+            downloads: saved.downloads,
+            max_downloads: saved.max_downloads,
+            expires_at_ms: saved.expires_at_ms,
+        })
+        .collect())
+}
+
+/// Stops serving a share and forgets it, if it was saved. Its content stays
+/// in the store.
+pub async fn stop_share(data_dir: String, id: String) -> Result<(), HoleError> {
+    let running = shares::stop(&id);
+    let saved = shares::remove(Path::new(&data_dir), &id).await?;
+    if !running && !saved {
+        return Err(HoleError::NotFound("No such share".into()));
+    }
+    Ok(())
+}
+
+async fn import_file(store: &FsStore, path: &Path, password: Option<&Password>, sink: &StreamSink<String>) -> Result<Hash> {
+    // 0. Encrypt File (optional), the store only ever sees the ciphertext
+    if password.is_some() {
+        let file = tokio::fs::File::open(path).await?;
+        return import_stream(store, ReaderStream::new(file), password, sink).await;
+    }
 
     sink.add("Importing...".to_string()).ok();
-    
-    // 1. Import File
     let import = store.add_path_with_opts(AddPathOptions {
-        path: path.clone(),
+        path: path.to_path_buf(),
         mode: ImportMode::TryReference,
         format: BlobFormat::Raw,
     });
@@ -46,204 +784,798 @@ pub async fn start_send(file_path: String, data_dir: String, sink: StreamSink<St
              file_hash = Some(t.hash());
         }
     }
-    let file_hash = file_hash.context("Import failed")?;
+    file_hash.context("Import failed")
+}
 
-    // 2. Import Metadata (Filename)
-    let filename = path.file_name().unwrap_or_default().to_string_lossy().to_string();
-    let meta_path = data_path.join("temp_meta_blob");
-    tokio::fs::write(&meta_path, filename.as_bytes()).await?;
+// Imports chunks straight into the store, encrypting them on the way if
+// there's a password. Nothing touches the disk outside the store.
+async fn import_stream<S>(store: &FsStore, chunks: S, password: Option<&Password>, sink: &StreamSink<String>) -> Result<Hash>
+where
+    S: futures::Stream<Item = std::io::Result<Bytes>> + Send + Sync + 'static,
+{
+    sink.add("Importing...".to_string()).ok();
+    let Some(password) = password else {
+        return add_stream(store, chunks).await;
+    };
+    sink.add("Encrypting...".to_string()).ok();
+    let (writer, reader) = tokio::io::duplex(64 * 1024);
+    let password = password.clone();
+    let encrypt = tokio::spawn(async move { crypto::encrypt_stream(&password, StreamReader::new(Box::pin(chunks)), writer).await });
+    let hash = add_stream(store, ReaderStream::new(reader)).await?;
+    encrypt.await??;
+    Ok(hash)
+}
 
-    let import_meta = store.add_path_with_opts(AddPathOptions {
-        path: meta_path.clone(),
-        mode: ImportMode::Copy,
-        format: BlobFormat::Raw,
-    });
-    let mut stream_meta = import_meta.stream().await;
-    let mut meta_hash = None;
-    while let Some(item) = stream_meta.next().await {
+async fn add_stream<S>(store: &FsStore, chunks: S) -> Result<Hash>
+where
+    S: futures::Stream<Item = std::io::Result<Bytes>> + Send + Sync + 'static,
+{
+    let mut stream = store.add_stream(chunks).await.stream().await;
+    let mut hash = None;
+    while let Some(item) = stream.next().await {
         if let iroh_blobs::api::blobs::AddProgressItem::Done(t) = item {
-             meta_hash = Some(t.hash());
+             hash = Some(t.hash());
         }
     }
-    let meta_hash = meta_hash.context("Meta import failed")?;
+    hash.context("Import failed")
+}
+
+// Imports a small blob like metadata, straight from memory.
+async fn import_bytes(store: &FsStore, bytes: Vec<u8>) -> Result<Hash> {
+    add_stream(store, futures::stream::once(async move { Ok(Bytes::from(bytes)) })).await
+}
 
-    // 3. Create HashSeq [meta, file]
-    let seq = HashSeq::from_iter([meta_hash, file_hash]);
-    let seq_path = data_path.join("temp_seq_blob");
-    tokio::fs::write(&seq_path, Bytes::from(seq)).await?;
+#[flutter_rust_bridge::frb(sync)]
+pub fn respond_to_peer(node_id: String, approve: bool) -> Result<(), HoleError> {
+    Ok(provider::respond_to_peer(&node_id, approve)?)
+}
 
-    let import_seq = store.add_path_with_opts(AddPathOptions {
-        path: seq_path.clone(),
-        mode: ImportMode::Copy,
-        format: BlobFormat::HashSeq,
-    });
-    let mut stream_seq = import_seq.stream().await;
-    let mut seq_hash = None;
-    while let Some(item) = stream_seq.next().await {
-        if let iroh_blobs::api::blobs::AddProgressItem::Done(t) = item {
-             seq_hash = Some(t.hash());
-        }
+// Waits for the sequence and its metadata blob to be complete in the store,
+// which lets the receiver parse them while the rest is still downloading.
+async fn read_seq_and_meta(store: &FsStore, hash: Hash) -> Result<(Vec<Hash>, Vec<u8>)> {
+    store.observe(hash).await_completion().await?;
+    let mut reader = store.reader(hash);
+    let mut seq_bytes = Vec::new();
+    reader.read_to_end(&mut seq_bytes).await?;
+
+    let seq = HashSeq::try_from(Bytes::from(seq_bytes))?;
+    let hashes: Vec<_> = seq.into_iter().collect();
+    if hashes.len() < 2 {
+         return Err(HoleError::MetadataInvalid("Invalid sequence length".into()).into());
     }
-    let seq_hash = seq_hash.context("Seq import failed")?;
 
-    let secret_key = get_secret();
-    let endpoint = Endpoint::builder()
-        .secret_key(secret_key)
-        .discovery(PkarrPublisher::n0_dns())
-        .alpns(vec![iroh_blobs::protocol::ALPN.to_vec()])
-        .bind().await?;
-        
-    let blobs = BlobsProtocol::new(&store, None); 
-    let router = iroh::protocol::Router::builder(endpoint)
-        .accept(iroh_blobs::ALPN, blobs.clone())
-        .spawn();
-
-    let ep = router.endpoint();
-    ep.online().await;
-
-    // Ticket now points to the HashSeq
-    let ticket = BlobTicket::new(ep.addr(), seq_hash, BlobFormat::HashSeq);
-    sink.add(format!("DEBUG: Generated Ticket Format: {:?}", ticket.format())).ok();
-    sink.add("TICKET:".to_string() + &ticket.to_string()).ok();
-    sink.add("Ready!".to_string()).ok();
-
-    futures::future::pending::<()>().await;
-    router.shutdown().await?;
-    Ok(())
+    store.observe(hashes[0]).await_completion().await?;
+    let mut reader_meta = store.reader(hashes[0]);
+    let mut meta_bytes = Vec::new();
+    reader_meta.read_to_end(&mut meta_bytes).await?;
+    Ok((hashes, meta_bytes))
 }
 
-pub async fn receive_file(ticket_str: String, data_dir: String, download_dir: String, sink: StreamSink<String>) -> Result<()> {
-    let ticket = BlobTicket::from_str(&ticket_str).context("Invalid ticket")?;
-    sink.add(format!("DEBUG: Received Ticket Format: {:?}", ticket.format())).ok();
-    sink.add("[V17-FORCE-REBUILD] Initializing...".to_string()).ok();
-    
-    let data_path = PathBuf::from(&data_dir).join("sendme_store_recv");
-    tokio::fs::create_dir_all(&data_path).await?;
-    let store = FsStore::load(&data_path).await?;
+async fn read_info(store: &FsStore, hash: Hash, password: Option<&Password>) -> Result<ShareInfo> {
+    let bytes = store.get_bytes(hash).await?;
+    match password {
+        Some(password) => ShareInfo::from_bytes(&crypto::decrypt_bytes(password, &bytes).await?),
+        None => ShareInfo::from_bytes(&bytes),
+    }
+}
 
+async fn bind_receiver(static_discovery: StaticProvider) -> Result<Endpoint> {
     let secret_key = get_secret();
     let endpoint = Endpoint::builder()
         .secret_key(secret_key)
         .discovery(PkarrPublisher::n0_dns())
+        .discovery(DnsDiscovery::n0_dns())
+        .discovery(static_discovery)
         .alpns(vec![iroh_blobs::protocol::ALPN.to_vec()])
         .bind().await?;
     
     endpoint.online().await;
-    sink.add("Connecting...".to_string()).ok();
+    Ok(endpoint)
+}
+
+#[derive(Debug, Clone)]
+pub struct TicketInfo {
+    /// NodeId of the sender.
+    pub sender: String,
+    pub version: u32,
+    /// Whether the share needs a password. Without one, nothing but the
+    /// sender and the version is known.
+    pub encrypted: bool,
+    pub is_text: bool,
+    /// One per file, in the order `ReceiveOptions::select_indices` refers to.
+    pub filenames: Vec<String>,
+    pub mime_types: Vec<String>,
+    /// Total size of the content, if the sender told it.
+    pub total_size: Option<u64>,
+}
+
+/// Fetches only the metadata of a share, so the user can decide whether to
+/// download it before spending bandwidth and storage on it.
+pub async fn inspect_ticket(ticket_str: String, data_dir: String, password: Option<String>) -> Result<TicketInfo, HoleError> {
+    let ShareTicket { version, blob: ticket } = ShareTicket::from_str(&ticket_str)?;
+
+    let store = store::open(&data_dir).await?;
+
+    let static_discovery = StaticProvider::new();
+    static_discovery.add_endpoint_info(ticket.addr().clone());
+    let endpoint = bind_receiver(static_discovery).await?;
+    let password = password.as_deref().filter(|p| !p.is_empty()).map(Password::new);
+    let res = inspect(&store, &endpoint, version, &ticket, password).await;
+    endpoint.close().await;
+    Ok(res?)
+}
+
+async fn inspect(store: &FsStore, endpoint: &Endpoint, version: u32, ticket: &BlobTicket, password: Option<Password>) -> Result<TicketInfo> {
+    let hash = ticket.hash();
+    let mut info = TicketInfo {
+        sender: ticket.addr().id.to_string(),
+        version,
+        encrypted: false,
+        is_text: false,
+        filenames: Vec::new(),
+        mime_types: Vec::new(),
+        total_size: None,
+    };
     let connection = endpoint.connect(ticket.addr().clone(), iroh_blobs::protocol::ALPN).await?;
-    
-    sink.add("Downloading...".to_string()).ok();
+
+    if version == 0 {
+        // Just enough to tell the type, and the last chunk to prove the size
+        let header_chunks = filetype::HEADER_LEN as u64 / 1024;
+        let request = GetRequest::blob_ranges(hash, ChunkRanges::chunks(0..header_chunks) | ChunkRanges::last_chunk());
+        store.remote().execute_get(connection, request).await?;
+        let mut header = Vec::new();
+        store.reader(hash).take(filetype::HEADER_LEN as u64).read_to_end(&mut header).await?;
+        let file_type = filetype::detect(&header).unwrap_or(FileType::BINARY);
+        info.filenames.push("received_".to_string() + &hex::encode(&hash.as_bytes()[..4]) + "." + file_type.ext);
+        info.mime_types.push(file_type.mime.to_string());
+        info.total_size = store.observe(hash).await?.validated_size();
+        return Ok(info);
+    }
+
+    // The sequence, the name and the info, but nothing of the file
+    let request = GetRequest::builder()
+        .root(ChunkRanges::all())
+        .child(0, ChunkRanges::all())
+        .child(2, ChunkRanges::all())
+        .build(hash);
+    store.remote().execute_get(connection, request).await?;
+    let (hashes, mut meta_bytes) = read_seq_and_meta(store, hash).await?;
+
+    if crypto::is_encrypted(&meta_bytes) {
+        info.encrypted = true;
+        let Some(password) = &password else {
+            return Ok(info);
+        };
+        meta_bytes = crypto::decrypt_bytes(password, &meta_bytes).await?;
+    }
+    let filename = String::from_utf8_lossy(&meta_bytes).to_string();
+
+    // Version 1 senders only sent the name
+    let share_info = match hashes.get(2) {
+        Some(info_hash) => read_info(store, *info_hash, password.as_ref()).await?,
+        None => ShareInfo::default(),
+    };
+    info.version = info.version.max(share_info.version.unwrap_or_default());
+    info.is_text = share_info.kind == ContentKind::Text;
+    info.total_size = share_info.size;
+    if share_info.items.is_empty() {
+        let mime = match share_info.mime {
+            Some(mime) => mime,
+            None => filetype::detect_named(&filename, &[]).mime.to_string(),
+        };
+        info.filenames.push(filename);
+        info.mime_types.push(mime);
+    }
+    for item in share_info.items {
+        let mime = match item.mime {
+            Some(mime) => mime,
+            None => filetype::detect_named(&item.name, &[]).mime.to_string(),
+        };
+        info.filenames.push(item.name);
+        info.mime_types.push(mime);
+    }
+    Ok(info)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransferDirection {
+    Send,
+    Receive,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransferOutcome {
+    Completed,
+    Failed,
+}
+
+/// A past transfer. Sends are recorded per download, receives per call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferRecord {
+    pub id: u64,
+    pub direction: TransferDirection,
+    /// NodeId of the other side.
+    pub peer: String,
+    /// Hash of the share, as in its ticket.
+    pub hash: String,
+    pub filenames: Vec<String>,
+    /// Sizes of the files, 0 where unknown.
+    pub sizes: Vec<u64>,
+    pub file_hashes: Vec<String>,
+    /// Unix time in milliseconds.
+    pub started_at_ms: u64,
+    pub duration_ms: u64,
+    pub outcome: TransferOutcome,
+    pub error: Option<String>,
+}
+
+impl TransferRecord {
+    fn new(direction: TransferDirection, hash: Hash) -> Self {
+        Self {
+            id: 0,
+            direction,
+            peer: String::new(),
+            hash: hash.to_string(),
+            filenames: Vec::new(),
+            sizes: Vec::new(),
+            file_hashes: Vec::new(),
+            started_at_ms: history::now_ms(),
+            duration_ms: 0,
+            outcome: TransferOutcome::Completed,
+            error: None,
+        }
+    }
+}
+
+/// Lists past transfers newest first, optionally of one direction only.
+pub async fn list_transfers(data_dir: String, direction: Option<TransferDirection>, offset: u32, limit: Option<u32>) -> Result<Vec<TransferRecord>, HoleError> {
+    Ok(history::list(Path::new(&data_dir), direction, offset as usize, limit.map(|limit| limit as usize)).await?)
+}
+
+pub async fn delete_transfer(data_dir: String, id: u64) -> Result<(), HoleError> {
+    if !history::delete(Path::new(&data_dir), id).await? {
+        return Err(HoleError::NotFound("No such transfer".into()));
+    }
+    Ok(())
+}
+
+/// Deletes all past transfers and returns how many there were. The files
+/// themselves stay where they are.
+pub async fn clear_transfers(data_dir: String) -> Result<u64, HoleError> {
+    Ok(history::clear(Path::new(&data_dir)).await?)
+}
+
+/// A tag in the store. Tagged content is kept, see `remove_tag`.
+#[derive(Debug, Clone)]
+pub struct ContentTag {
+    /// `send/<unix ms>/<name>`, `recv/<hash>` or `pin/<hash>`.
+    pub name: String,
+    pub hash: String,
+    /// Whether the tag is for a whole share rather than a single blob.
+    pub is_share: bool,
+}
+
+/// Lists the tags in the store, all or those starting with `prefix`, e.g.
+/// `send/` for everything shared.
+pub async fn list_tags(data_dir: String, prefix: Option<String>) -> Result<Vec<ContentTag>, HoleError> {
+    let store = store::open(&data_dir).await?;
+    let tags = store::list(&store, prefix.as_deref()).await?;
+    Ok(tags
+        .into_iter()
+        .map(|tag| ContentTag {
+            name: String::from_utf8_lossy(tag.name.as_ref()).to_string(),
+            hash: tag.hash.to_string(),
+            is_share: tag.format == BlobFormat::HashSeq,
+        })
+        .collect())
+}
+
+/// Keeps the content of a tag until it is unpinned by removing the returned
+/// `pin/` tag, whatever happens to the original.
+pub async fn pin_tag(data_dir: String, name: String) -> Result<String, HoleError> {
+    let store = store::open(&data_dir).await?;
+    Ok(store::pin(&store, &name).await?)
+}
+
+/// Removes a tag. The store doesn't collect garbage, so content without any
+/// tags left stays on disk until the data dir is cleared.
+pub async fn remove_tag(data_dir: String, name: String) -> Result<(), HoleError> {
+    let store = store::open(&data_dir).await?;
+    Ok(store::remove(&store, &name).await?)
+}
+
+/// Reads `len` bytes at `offset` of a file in a share, the one at `index` or
+/// the first, downloading only the chunks that cover them.
+pub async fn read_remote_range(ticket_str: String, data_dir: String, index: Option<u32>, offset: u64, len: u64) -> Result<Vec<u8>, HoleError> {
+    if len > MAX_MEMORY_RECEIVE {
+        return Err(HoleError::TooLarge("Range too large to read into memory".into()));
+    }
+    let file = open_remote_file(&ticket_str, &data_dir, index).await?;
+    let res = file.read(offset, len).await;
+    file.close().await;
+    Ok(res?)
+}
+
+/// Serves a file of a share over HTTP on localhost, so a media player can
+/// play it while it downloads. Emits `STREAM_URL:<url>` once listening and
+/// runs until `stop_stream_server` is called with that URL.
+pub async fn start_stream_server(ticket_str: String, data_dir: String, index: Option<u32>, sink: StreamSink<String>) -> Result<(), HoleError> {
+    let file = open_remote_file(&ticket_str, &data_dir, index).await.map_err(|e| error::report(&sink, e))?;
+    sink.add("Filename: ".to_string() + &file.name).ok();
+    sink.add("MIME: ".to_string() + &file.mime).ok();
+    let res = ranged::serve(file, |url| {
+        sink.add("STREAM_URL:".to_string() + url).ok();
+    })
+    .await;
+    res.map_err(|e| error::report(&sink, e))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn stop_stream_server(url: String) -> Result<(), HoleError> {
+    Ok(ranged::stop(&url)?)
+}
+
+// Finds a file in a share and opens it for ranged reads, with nothing but
+// the metadata downloaded.
+async fn open_remote_file(ticket_str: &str, data_dir: &str, index: Option<u32>) -> Result<RemoteFile> {
+    let ShareTicket { version, blob: ticket } = ShareTicket::from_str(ticket_str)?;
+
+    let store = store::open(data_dir).await?;
+
+    let static_discovery = StaticProvider::new();
+    static_discovery.add_endpoint_info(ticket.addr().clone());
+    let endpoint = bind_receiver(static_discovery).await?;
+    let res = open_in(&store, &endpoint, version, &ticket, index.unwrap_or_default()).await;
+    if res.is_err() {
+        endpoint.close().await;
+    }
+    res
+}
+
+async fn open_in(store: &FsStore, endpoint: &Endpoint, version: u32, ticket: &BlobTicket, index: u32) -> Result<RemoteFile> {
+    let open = |hash, name, mime, size| RemoteFile::open(store.clone(), endpoint.clone(), ticket.addr().clone(), hash, name, mime, size);
+
     let hash = ticket.hash();
-    
-    if ticket.format() == BlobFormat::HashSeq {
-        // --- New Protocol: HashSeq [Meta, File] ---
-        
-        // 1. Download Sequence
-        let get_seq = store.remote().execute_get(connection.clone(), GetRequest::blob(hash));
-        let mut stream = get_seq.stream();
-        while let Some(item) = stream.next().await {
-             if let iroh_blobs::api::remote::GetProgressItem::Error(e) = item {
-                 sink.add("Error Seq: ".to_string() + &e.to_string()).ok();
-                 return Err(anyhow::anyhow!("Download Seq failed"));
-             }
+    if version == 0 {
+        if index != 0 {
+            return Err(HoleError::NotFound(format!("No such file: #{}", index)).into());
         }
-        
-        // 2. Parse Sequence
-        let mut reader = store.reader(hash);
-        let mut seq_bytes = Vec::new();
-        reader.read_to_end(&mut seq_bytes).await?;
-        
-        // HashSeq usually implements Into<Bytes> via `into_bytes()` or `to_bytes()`.
-        // In iroh-blobs 0.97, it's `into_bytes()`.
-        let seq = HashSeq::try_from(Bytes::from(seq_bytes))?;
-        let hashes: Vec<_> = seq.into_iter().collect();
-        
-        if hashes.len() < 2 {
-             return Err(anyhow::anyhow!("Invalid sequence length"));
+        // Named by its first bytes, like `receive` does
+        let mut file = open(hash, String::new(), String::new(), None).await?;
+        let header = file.read(0, filetype::HEADER_LEN as u64).await?;
+        let file_type = filetype::detect(&header).unwrap_or(FileType::BINARY);
+        file.name = "received_".to_string() + &hex::encode(&hash.as_bytes()[..4]) + "." + file_type.ext;
+        file.mime = file_type.mime.to_string();
+        return Ok(file);
+    }
+
+    let connection = endpoint.connect(ticket.addr().clone(), iroh_blobs::protocol::ALPN).await?;
+    let request = GetRequest::builder()
+        .root(ChunkRanges::all())
+        .child(0, ChunkRanges::all())
+        .child(2, ChunkRanges::all())
+        .build(hash);
+    store.remote().execute_get(connection, request).await?;
+    let (hashes, meta_bytes) = read_seq_and_meta(store, hash).await?;
+    // Chunks of an encrypted file can't be decrypted on their own
+    if crypto::is_encrypted(&meta_bytes) {
+        return Err(HoleError::Unsupported("Streaming is not supported for encrypted shares".into()).into());
+    }
+    let filename = String::from_utf8_lossy(&meta_bytes).to_string();
+    let info = match hashes.get(2) {
+        Some(info_hash) => read_info(store, *info_hash, None).await?,
+        None => ShareInfo::default(),
+    };
+    let items = share_items(&filename, &info);
+    let item = items.into_iter().nth(index as usize).context(HoleError::NotFound(format!("No such file: #{}", index)))?;
+    let file_hash = *hashes.get(item.child as usize).context(HoleError::MetadataInvalid("Metadata invalid".into()))?;
+
+    let mut file = open(file_hash, item.name, item.mime.clone().unwrap_or_default(), item.size).await?;
+    if item.mime.is_none() {
+        let header = file.read(0, filetype::HEADER_LEN as u64).await?;
+        file.mime = filetype::detect_named(&file.name, &header).mime.to_string();
+    }
+    Ok(file)
+}
+
+pub async fn receive_file(ticket_str: String, data_dir: String, download_dir: String, sink: StreamSink<String>) -> Result<(), HoleError> {
+    receive_file_with_options(ticket_str, data_dir, download_dir, ReceiveOptions::default(), sink).await
+}
+
+pub async fn receive_file_with_options(ticket_str: String, data_dir: String, download_dir: String, options: ReceiveOptions, sink: StreamSink<String>) -> Result<(), HoleError> {
+    let mut target = Target::Dir(PathBuf::from(download_dir));
+    receive(ticket_str, data_dir, &mut target, options, sink).await
+}
+
+/// Receives into memory instead of a file, for previews and small files.
+pub async fn receive_bytes(ticket_str: String, data_dir: String, options: ReceiveOptions, sink: StreamSink<String>) -> Result<Vec<u8>, HoleError> {
+    let mut target = Target::Memory(Vec::new());
+    receive(ticket_str, data_dir, &mut target, options, sink).await?;
+    match target {
+        Target::Memory(bytes) => Ok(bytes),
+        _ => unreachable!(),
+    }
+}
+
+/// Streams the received file to `chunks` instead of saving it, leaving the
+/// destination to the app, e.g. MediaStore or Photos.
+pub async fn receive_to_stream(ticket_str: String, data_dir: String, options: ReceiveOptions, chunks: StreamSink<Vec<u8>>, sink: StreamSink<String>) -> Result<(), HoleError> {
+    let mut target = Target::Stream(chunks);
+    receive(ticket_str, data_dir, &mut target, options, sink).await
+}
+
+// Single-file shares are one item named by the metadata.
+fn share_items(filename: &str, info: &ShareInfo) -> Vec<Item> {
+    if !info.items.is_empty() {
+        return info.items.clone();
+    }
+    vec![Item {
+        name: filename.to_string(),
+        child: 1,
+        mime: info.mime.clone(),
+        size: info.size,
+    }]
+}
+
+// Names the files of a share by their paths relative to the closest folder
+// they are all in, with `/` between folders. Files in one folder just get
+// their file names. Two files with the same name are refused, receivers
+// would save one over the other.
+fn item_names(paths: &[PathBuf]) -> Result<Vec<String>> {
+    let parents: Vec<Vec<_>> = paths.iter().map(|path| path.parent().into_iter().flat_map(Path::components).collect()).collect();
+    let shortest = parents.iter().map(Vec::len).min().unwrap_or_default();
+    let common = (0..shortest).take_while(|i| parents.iter().all(|parent| parent[*i] == parents[0][*i])).count();
+    let names: Vec<String> = paths
+        .iter()
+        .map(|path| {
+            let parts: Vec<_> = path
+                .components()
+                .skip(common)
+                .filter_map(|c| match c {
+                    std::path::Component::Normal(part) => Some(part.to_string_lossy()),
+                    _ => None,
+                })
+                .collect();
+            parts.join("/")
+        })
+        .collect();
+    let mut seen = HashSet::new();
+    if let Some(name) = names.iter().find(|name| !seen.insert(*name)) {
+        return Err(HoleError::InvalidInput(format!("More than one file named {}", name)).into());
+    }
+    Ok(names)
+}
+
+// Picks files from a share
+enum Selector {
+    Index(u32),
+    Path(String),
+}
+
+// Narrows the items down to the selection, keeping their order.
+fn select_items(items: Vec<Item>, selection: &[Selector]) -> Result<Vec<Item>> {
+    if selection.is_empty() {
+        return Ok(items);
+    }
+    let mut selected = vec![false; items.len()];
+    for selector in selection {
+        let index = match selector {
+            Selector::Index(index) => Some(*index as usize).filter(|index| *index < items.len()),
+            Selector::Path(path) => items.iter().position(|item| item.name == *path),
+        };
+        let Some(index) = index else {
+            let message = match selector {
+                Selector::Index(index) => format!("No such file: #{}", index),
+                Selector::Path(path) => format!("No such file: {}", path),
+            };
+            return Err(HoleError::NotFound(message).into());
+        };
+        selected[index] = true;
+    }
+    Ok(items.into_iter().zip(selected).filter(|(_, selected)| *selected).map(|(item, _)| item).collect())
+}
+
+// Where received files go
+enum Target {
+    Dir(PathBuf),
+    Memory(Vec<u8>),
+    Stream(StreamSink<Vec<u8>>),
+}
+
+// Writes a received blob to the target, decrypting it on the way. Returns
+// the path for targets on disk.
+async fn export(store: &FsStore, hash: Hash, password: Option<&Password>, filename: &str, target: &mut Target) -> Result<Option<PathBuf>> {
+    let reader = store.reader(hash);
+    match target {
+        Target::Dir(dir) => {
+            // Names come from the sender, they must not point outside the directory
+            let relative = Path::new(filename);
+            if !relative.components().all(|c| matches!(c, std::path::Component::Normal(_))) {
+                return Err(HoleError::MetadataInvalid(format!("Invalid file name: {}", filename)).into());
+            }
+            let path = dir.join(relative);
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            let mut file = tokio::fs::File::create(&path).await?;
+            if let Err(e) = write_blob(reader, password, &mut file).await {
+                drop(file);
+                tokio::fs::remove_file(&path).await.ok();
+                return Err(e);
+            }
+            Ok(Some(path))
         }
-        let meta_hash = hashes[0];
+        Target::Memory(bytes) => {
+            let size = store.observe(hash).await?.validated_size().unwrap_or_default();
+            if size > MAX_MEMORY_RECEIVE {
+                return Err(HoleError::TooLarge("File too large to receive into memory".into()).into());
+            }
+            write_blob(reader, password, bytes).await?;
+            Ok(None)
+        }
+        Target::Stream(chunks) => {
+            let mut writer = BufWriter::with_capacity(STREAM_CHUNK_LEN, SinkWriter(chunks.clone()));
+            write_blob(reader, password, &mut writer).await?;
+            writer.flush().await?;
+            Ok(None)
+        }
+    }
+}
+
+async fn write_blob<R, W>(mut reader: R, password: Option<&Password>, writer: &mut W) -> Result<()>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    match password {
+        Some(password) => crypto::decrypt_stream(password, reader, writer).await,
+        None => {
+            tokio::io::copy(&mut reader, writer).await?;
+            Ok(())
+        }
+    }
+}
+
+// Receives and records the outcome in the history.
+async fn receive(ticket_str: String, data_dir: String, target: &mut Target, options: ReceiveOptions, sink: StreamSink<String>) -> Result<(), HoleError> {
+    let ticket = ShareTicket::from_str(&ticket_str).map_err(|e| error::report(&sink, e))?;
+    let mut record = TransferRecord::new(TransferDirection::Receive, ticket.blob.hash());
+    record.peer = ticket.blob.addr().id.to_string();
+    let started = Instant::now();
+    let res = receive_share(ticket, &data_dir, target, options, &mut record, sink.clone()).await;
+    record.duration_ms = started.elapsed().as_millis() as u64;
+    if let Err(e) = &res {
+        record.outcome = TransferOutcome::Failed;
+        record.error = Some(e.to_string());
+    }
+    history::add(Path::new(&data_dir), record).await.ok();
+    res.map_err(|e| error::report(&sink, e))
+}
+
+async fn receive_share(ticket: ShareTicket, data_dir: &str, target: &mut Target, options: ReceiveOptions, record: &mut TransferRecord, sink: StreamSink<String>) -> Result<()> {
+    let ShareTicket { version, blob: ticket } = ticket;
+    sink.add(format!("DEBUG: Received Ticket Version: {}", version)).ok();
+    sink.add("[V17-FORCE-REBUILD] Initializing...".to_string()).ok();
+    
+    let store = store::open(data_dir).await?;
+
+    let static_discovery = StaticProvider::new();
+    let providers = download::collect_providers(&ticket, &options.extra_sources, &static_discovery)?;
+
+    let endpoint = bind_receiver(static_discovery).await?;
+
+    let _paths: Vec<_> = providers
+        .ids
+        .iter()
+        .map(|id| PathReporter::spawn(endpoint.clone(), *id, sink.clone()))
+        .collect();
+    
+    let hash = ticket.hash();
+    // Tagged up front, so a partial download is kept to resume
+    store::tag_received(&store, HashAndFormat { hash, format: ticket.format() }).await?;
+    let retry = RetryPolicy::new(options.max_retries, options.retry_delay_ms);
+    let fetch = |request: GetRequest, total: Option<u64>| {
+        let (store, endpoint, sink, providers) = (&store, &endpoint, &sink, &providers);
+        async move { download::fetch(store, endpoint, providers, request, retry, total, sink).await }
+    };
+    let selection: Vec<_> = options
+        .select_indices
+        .iter()
+        .map(|index| Selector::Index(*index))
+        .chain(options.select_paths.iter().map(|path| Selector::Path(path.clone())))
+        .collect();
+    if version >= 1 {
+        // --- Version 1+: HashSeq [Meta, File] or [Meta, File, Info, More Files...] ---
+
+        // 1. The sequence, the name and the info first, to know what to
+        // fetch and to refuse what can't be received before fetching it
+        let request = GetRequest::builder()
+            .root(ChunkRanges::all())
+            .child(0, ChunkRanges::all())
+            .child(2, ChunkRanges::all())
+            .build(hash);
+        fetch(request, None).await?;
+        let (hashes, mut meta_bytes) = read_seq_and_meta(&store, hash).await?;
         let file_hash = hashes[1];
-        
-        // 3. Download Metadata
-        let get_meta = store.remote().execute_get(connection.clone(), GetRequest::blob(meta_hash));
-        let mut stream_meta = get_meta.stream();
-        while let Some(item) = stream_meta.next().await {
-             if let iroh_blobs::api::remote::GetProgressItem::Error(e) = item {
-                 sink.add("Error Meta: ".to_string() + &e.to_string()).ok();
-                 // Continue? Might fail.
-             }
-        }
-        let mut reader_meta = store.reader(meta_hash);
-        let mut meta_bytes = Vec::new();
-        reader_meta.read_to_end(&mut meta_bytes).await?;
+
+        let password = if crypto::is_encrypted(&meta_bytes) {
+            let Some(password) = options.password.as_deref().filter(|p| !p.is_empty()).map(Password::new) else {
+                return Err(HoleError::PasswordRequired("Password required".into()).into());
+            };
+            meta_bytes = crypto::decrypt_bytes(&password, &meta_bytes).await?;
+            Some(password)
+        } else {
+            None
+        };
         let filename = String::from_utf8_lossy(&meta_bytes).to_string();
         sink.add("Filename: ".to_string() + &filename).ok();
 
-        // 4. Download File
-        let get_file = store.remote().execute_get(connection.clone(), GetRequest::blob(file_hash));
-        let mut stream_file = get_file.stream();
-        while let Some(item) = stream_file.next().await {
-             if let iroh_blobs::api::remote::GetProgressItem::Error(e) = item {
-                 sink.add("Error File: ".to_string() + &e.to_string()).ok();
-                 return Err(anyhow::anyhow!("Download File failed"));
-             }
+        // Version 1 senders only sent [meta, file]
+        let info = match hashes.get(2) {
+            Some(info_hash) => read_info(&store, *info_hash, password.as_ref()).await?,
+            None if version >= 2 => {
+                return Err(HoleError::MetadataInvalid("Metadata invalid".into()).into());
+            }
+            None => ShareInfo::default(),
+        };
+
+        let items = share_items(&filename, &info);
+        if let Some(index) = items.iter().position(|item| hashes.get(item.child as usize).is_none()) {
+            return Err(HoleError::MetadataInvalid(format!("Metadata invalid: item {} is missing", index)).into());
+        }
+        let items = select_items(items, &selection)?;
+        record.filenames = items.iter().map(|item| item.name.clone()).collect();
+        record.sizes = items.iter().map(|item| item.size.unwrap_or_default()).collect();
+        record.file_hashes = items.iter().map(|item| hashes[item.child as usize].to_string()).collect();
+        if items.len() > 1 && !matches!(target, Target::Dir(_)) {
+            let message = "Select a single file to receive into memory or a stream";
+            return Err(HoleError::InvalidInput(message.into()).into());
+        }
+        // Sizes from version 1 senders are only known once fetched, and
+        // checked then
+        let total = items.iter().map(|item| item.size).sum::<Option<u64>>();
+        let is_text = info.kind == ContentKind::Text && matches!(target, Target::Dir(_));
+        if is_text && total.unwrap_or_default() > MAX_TEXT_LEN {
+            return Err(HoleError::TooLarge("Text too large to receive".into()).into());
+        }
+        if matches!(target, Target::Memory(_)) && total.unwrap_or_default() > MAX_MEMORY_RECEIVE {
+            return Err(HoleError::TooLarge("File too large to receive into memory".into()).into());
         }
-        
-        // 5. Export
-        let export_path = PathBuf::from(&download_dir).join(&filename);
-        let mut reader_file = store.reader(file_hash);
-        let mut file = tokio::fs::File::create(&export_path).await?;
-        tokio::io::copy(&mut reader_file, &mut file).await?;
 
-        sink.add("SUCCESS:Saved as ".to_string() + &filename).ok();
-        sink.add("Path: ".to_string() + &export_path.display().to_string()).ok();
+        // 2. Fetch the selected files, all without a selection, with
+        // progress over them
+        let mut request = GetRequest::builder().root(ChunkRanges::all());
+        for item in &items {
+            request = request.child(item.child, ChunkRanges::all());
+        }
+        fetch(request.build(hash), total).await?;
 
-    } else {
-        // --- Old Protocol: Raw Blob ---
-        
-        let get = store.remote().execute_get(connection, GetRequest::blob(hash));
-        let mut stream = get.stream();
-        while let Some(item) = stream.next().await {
-             if let iroh_blobs::api::remote::GetProgressItem::Error(e) = item {
-                 sink.add("Error: ".to_string() + &e.to_string()).ok();
-                 return Err(anyhow::anyhow!("Download failed"));
-             }
+        // Text goes straight to the app, nothing is saved. Into memory or a
+        // stream it is received like a file.
+        if is_text {
+            // Checked again before reading it, the sender decides what is text
+            let size = store.observe(file_hash).await?.validated_size().unwrap_or_default();
+            if size.max(info.size.unwrap_or_default()) > MAX_TEXT_LEN {
+                return Err(HoleError::TooLarge("Text too large to receive".into()).into());
+            }
+            let bytes = store.get_bytes(file_hash).await?;
+            let bytes = match &password {
+                Some(password) => crypto::decrypt_bytes(password, &bytes).await?,
+                None => bytes.to_vec(),
+            };
+            sink.add("SUCCESS:Received text".to_string()).ok();
+            sink.add("MIME: ".to_string() + info.mime.as_deref().unwrap_or("text/plain")).ok();
+            sink.add("TEXT:".to_string() + &String::from_utf8_lossy(&bytes)).ok();
+            endpoint.close().await;
+            return Ok(());
+        }
+
+        // 3. Export
+        if password.is_some() {
+            sink.add("Decrypting...".to_string()).ok();
+        }
+        for item in &items {
+            let item_hash = hashes[item.child as usize];
+            let export_path = export(&store, item_hash, password.as_ref(), &item.name, target).await?;
+
+            let mime = match (&item.mime, &export_path) {
+                (Some(mime), _) => mime.clone(),
+                (None, Some(path)) => filetype::detect_file(path).await.mime.to_string(),
+                (None, None) => {
+                    let mut header = Vec::new();
+                    if password.is_none() {
+                        store.reader(item_hash).take(filetype::HEADER_LEN as u64).read_to_end(&mut header).await?;
+                    }
+                    filetype::detect_named(&item.name, &header).mime.to_string()
+                }
+            };
+
+            match &export_path {
+                Some(path) => {
+                    sink.add("SUCCESS:Saved as ".to_string() + &item.name).ok();
+                    sink.add("MIME: ".to_string() + &mime).ok();
+                    sink.add("Path: ".to_string() + &path.display().to_string()).ok();
+                }
+                None => {
+                    sink.add("SUCCESS:Received ".to_string() + &item.name).ok();
+                    sink.add("MIME: ".to_string() + &mime).ok();
+                }
+            }
         }
 
+    } else {
+        // --- Version 0: Raw Blob ---
+
+        fetch(GetRequest::from(HashAndFormat::raw(hash)), None).await?;
+
         sink.add("Analyzing file header...".to_string()).ok();
         
-        let mut reader = store.reader(hash);
-        let mut header = [0u8; 12]; // 读 12 字节涵盖 WebP
-        let n = reader.read(&mut header).await?;
+        let mut header = Vec::new();
+        store.reader(hash).take(filetype::HEADER_LEN as u64).read_to_end(&mut header).await?;
         
         // 打印 Hex 日志方便调试
-        sink.add("Header Hex: ".to_string() + &hex::encode(&header[..n]) + "\n").ok();
+        sink.add("Header Hex: ".to_string() + &hex::encode(&header[..header.len().min(16)]) + "\n").ok();
         
-        let mut ext = "bin";
-        if n >= 3 && &header[0..3] == b"\xff\xd8\xff" {
-            ext = "jpg";
-        } else if n >= 8 && &header[0..8] == b"\x89PNG\r\n\x1a\n" {
-            ext = "png";
-        } else if n >= 6 && (&header[0..6] == b"GIF87a" || &header[0..6] == b"GIF89a") {
-            ext = "gif";
-        } else if n >= 12 && &header[0..4] == b"RIFF" && &header[8..12] == b"WEBP" {
-            ext = "webp";
-        } else if n >= 4 && &header[0..4] == b"%PDF" {
-            ext = "pdf";
-        }
+        let file_type = filetype::detect(&header).unwrap_or(FileType::BINARY);
+        let ext = file_type.ext;
 
         let export_filename = "received_".to_string() + &hex::encode(&hash.as_bytes()[..4]) + "." + ext;
-        let export_path = PathBuf::from(&download_dir).join(export_filename);
-        
-        let mut reader = store.reader(hash);
-        let mut file = tokio::fs::File::create(&export_path).await?;
-        tokio::io::copy(&mut reader, &mut file).await?;
-
-        sink.add("SUCCESS:Saved as .".to_string() + ext).ok();
-        sink.add("Path: ".to_string() + &export_path.display().to_string()).ok();
+        record.filenames = vec![export_filename.clone()];
+        record.sizes = vec![store.observe(hash).await?.validated_size().unwrap_or_default()];
+        record.file_hashes = vec![hash.to_string()];
+        match export(&store, hash, None, &export_filename, target).await? {
+            Some(export_path) => {
+                sink.add("SUCCESS:Saved as .".to_string() + ext).ok();
+                sink.add("MIME: ".to_string() + file_type.mime).ok();
+                sink.add("Path: ".to_string() + &export_path.display().to_string()).ok();
+            }
+            None => {
+                sink.add("SUCCESS:Received ".to_string() + &export_filename).ok();
+                sink.add("MIME: ".to_string() + file_type.mime).ok();
+            }
+        }
 
     }
 
     endpoint.close().await;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items() -> Vec<Item> {
+        ["a.txt", "dir/b.jpg", "c.pdf"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| Item { name: name.to_string(), child: i as u64 + 1, mime: None, size: None })
+            .collect()
+    }
+
+    fn names(items: Vec<Item>) -> Vec<String> {
+        items.into_iter().map(|item| item.name).collect()
+    }
+
+    #[test]
+    fn selects_items() {
+        assert_eq!(names(select_items(items(), &[]).unwrap()).len(), 3);
+        // Share order is kept, duplicates collapse
+        let selection = [Selector::Index(2), Selector::Path("a.txt".to_string()), Selector::Index(0)];
+        assert_eq!(names(select_items(items(), &selection).unwrap()), ["a.txt", "c.pdf"]);
+        assert!(select_items(items(), &[Selector::Index(3)]).is_err());
+        assert!(select_items(items(), &[Selector::Path("b.jpg".to_string())]).is_err());
+    }
+
+    #[test]
+    fn names_items() {
+        let paths = |paths: &[&str]| paths.iter().map(PathBuf::from).collect::<Vec<_>>();
+        assert_eq!(item_names(&paths(&["/a/x.txt", "/a/y.txt"])).unwrap(), ["x.txt", "y.txt"]);
+        assert_eq!(item_names(&paths(&["/a/b/f.txt", "/a/c/d/f.txt"])).unwrap(), ["b/f.txt", "c/d/f.txt"]);
+        assert_eq!(item_names(&paths(&["/a/f.txt", "/a/b/g.txt"])).unwrap(), ["f.txt", "b/g.txt"]);
+        let e = item_names(&paths(&["/a/f.txt", "/a/f.txt"])).unwrap_err();
+        assert!(matches!(e.downcast_ref::<HoleError>(), Some(HoleError::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,217 @@
+use crate::api::HoleError;
+use anyhow::{anyhow, bail, Result};
+use argon2::Argon2;
+use chacha20poly1305::aead::stream::{DecryptorBE32, EncryptorBE32};
+use chacha20poly1305::{ChaCha20Poly1305, Key, KeyInit};
+use rand::RngCore;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+// Encrypted blob layout: MAGIC | salt | nonce prefix | STREAM chunks
+const MAGIC: &[u8; 8] = b"HOLEENC1";
+const SALT_LEN: usize = 16;
+const NONCE_PREFIX_LEN: usize = 7;
+const HEADER_LEN: usize = MAGIC.len() + SALT_LEN + NONCE_PREFIX_LEN;
+const CHUNK_LEN: usize = 64 * 1024;
+const TAG_LEN: usize = 16;
+
+pub fn is_encrypted(data: &[u8]) -> bool {
+    data.len() >= HEADER_LEN && data.starts_with(MAGIC)
+}
+
+/// A password and the keys derived from it. Key derivation is slow on
+/// purpose, so blobs encrypted with the same `Password` share a salt and a
+/// key, and keys for salts seen before are reused for decrypting.
+#[derive(Clone)]
+pub struct Password {
+    password: Arc<str>,
+    // For encrypting, random per `Password`
+    salt: [u8; SALT_LEN],
+    keys: Arc<Mutex<HashMap<[u8; SALT_LEN], Key>>>,
+}
+
+impl Password {
+    pub fn new(password: &str) -> Self {
+        let mut salt = [0u8; SALT_LEN];
+        rand::rng().fill_bytes(&mut salt);
+        Self {
+            password: password.into(),
+            salt,
+            keys: Default::default(),
+        }
+    }
+
+    // Derives the key for `salt` off the async runtime, once.
+    async fn key(&self, salt: [u8; SALT_LEN]) -> Result<Key> {
+        if let Some(key) = self.keys.lock().unwrap().get(&salt) {
+            return Ok(*key);
+        }
+        let password = self.password.clone();
+        let key = tokio::task::spawn_blocking(move || {
+            let mut key = Key::default();
+            Argon2::default()
+                .hash_password_into(password.as_bytes(), &salt, &mut key)
+                .map_err(|e| anyhow!("Key derivation failed: {}", e))?;
+            Ok::<_, anyhow::Error>(key)
+        })
+        .await??;
+        self.keys.lock().unwrap().insert(salt, key);
+        Ok(key)
+    }
+}
+
+pub async fn encrypt_stream<R, W>(password: &Password, mut reader: R, mut writer: W) -> Result<()>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    // The key is shared, the nonce prefix keeps the blobs apart
+    let salt = password.salt;
+    let mut nonce = [0u8; NONCE_PREFIX_LEN];
+    rand::rng().fill_bytes(&mut nonce);
+
+    let cipher = ChaCha20Poly1305::new(&password.key(salt).await?);
+    let mut encryptor = EncryptorBE32::from_aead(cipher, nonce.as_ref().into());
+
+    writer.write_all(MAGIC).await?;
+    writer.write_all(&salt).await?;
+    writer.write_all(&nonce).await?;
+
+    // Every chunk but the last is exactly CHUNK_LEN, so read ahead one chunk
+    // to know when we've reached the end.
+    let mut current = vec![0u8; CHUNK_LEN];
+    let mut n = read_full(&mut reader, &mut current).await?;
+    loop {
+        let mut next = vec![0u8; CHUNK_LEN];
+        let m = if n == CHUNK_LEN { read_full(&mut reader, &mut next).await? } else { 0 };
+        if m == 0 {
+            let ct = encryptor
+                .encrypt_last(&current[..n])
+                .map_err(|_| anyhow!("Encryption failed"))?;
+            writer.write_all(&ct).await?;
+            break;
+        }
+        let ct = encryptor
+            .encrypt_next(&current[..n])
+            .map_err(|_| anyhow!("Encryption failed"))?;
+        writer.write_all(&ct).await?;
+        current = next;
+        n = m;
+    }
+    writer.flush().await?;
+    Ok(())
+}
+
+pub async fn decrypt_stream<R, W>(password: &Password, mut reader: R, mut writer: W) -> Result<()>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let mut header = [0u8; HEADER_LEN];
+    if read_full(&mut reader, &mut header).await? != HEADER_LEN || !header.starts_with(MAGIC) {
+        bail!("Not an encrypted blob");
+    }
+    let salt = header[MAGIC.len()..MAGIC.len() + SALT_LEN].try_into()?;
+    let nonce = &header[MAGIC.len() + SALT_LEN..];
+
+    let cipher = ChaCha20Poly1305::new(&password.key(salt).await?);
+    let mut decryptor = DecryptorBE32::from_aead(cipher, nonce.into());
+
+    let mut first = true;
+    let mut current = vec![0u8; CHUNK_LEN + TAG_LEN];
+    let mut n = read_full(&mut reader, &mut current).await?;
+    loop {
+        let mut next = vec![0u8; CHUNK_LEN + TAG_LEN];
+        let m = if n == current.len() { read_full(&mut reader, &mut next).await? } else { 0 };
+        if m == 0 {
+            let pt = decryptor
+                .decrypt_last(&current[..n])
+                .map_err(|_| chunk_error(first))?;
+            writer.write_all(&pt).await?;
+            break;
+        }
+        let pt = decryptor
+            .decrypt_next(&current[..n])
+            .map_err(|_| chunk_error(first))?;
+        writer.write_all(&pt).await?;
+        first = false;
+        current = next;
+        n = m;
+    }
+    writer.flush().await?;
+    Ok(())
+}
+
+// The first chunk is authenticated with the password-derived key, so failing
+// there almost always means the password is wrong.
+fn chunk_error(first: bool) -> anyhow::Error {
+    if first {
+        HoleError::WrongPassword("Wrong password".into()).into()
+    } else {
+        anyhow!("Decryption failed: data corrupted")
+    }
+}
+
+pub async fn encrypt_bytes(password: &Password, data: &[u8]) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    encrypt_stream(password, data, &mut out).await?;
+    Ok(out)
+}
+
+pub async fn decrypt_bytes(password: &Password, data: &[u8]) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    decrypt_stream(password, data, &mut out).await?;
+    Ok(out)
+}
+
+async fn read_full<R: AsyncRead + Unpin>(reader: &mut R, buf: &mut [u8]) -> Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        let n = reader.read(&mut buf[filled..]).await?;
+        if n == 0 {
+            break;
+        }
+        filled += n;
+    }
+    Ok(filled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn round_trip(len: usize) {
+        let data: Vec<u8> = (0..len).map(|i| i as u8).collect();
+        let encrypted = encrypt_bytes(&Password::new("secret"), &data).await.unwrap();
+        assert!(is_encrypted(&encrypted));
+        // A fresh password has to derive the key from the salt in the header
+        let decrypted = decrypt_bytes(&Password::new("secret"), &encrypted).await.unwrap();
+        assert_eq!(decrypted, data);
+    }
+
+    #[tokio::test]
+    async fn round_trips() {
+        round_trip(0).await;
+        round_trip(CHUNK_LEN).await;
+        round_trip(CHUNK_LEN + 1).await;
+    }
+
+    #[tokio::test]
+    async fn wrong_password() {
+        let encrypted = encrypt_bytes(&Password::new("secret"), b"data").await.unwrap();
+        let e = decrypt_bytes(&Password::new("guess"), &encrypted).await.unwrap_err();
+        assert!(matches!(e.downcast_ref::<HoleError>(), Some(HoleError::WrongPassword(_))));
+    }
+
+    #[tokio::test]
+    async fn truncation() {
+        let password = Password::new("secret");
+        let encrypted = encrypt_bytes(&password, &vec![1u8; 3 * CHUNK_LEN]).await.unwrap();
+        // Cut after whole chunks, so only the missing last chunk flag tells
+        for chunks in [1, 2] {
+            let truncated = &encrypted[..HEADER_LEN + chunks * (CHUNK_LEN + TAG_LEN)];
+            assert!(decrypt_bytes(&password, truncated).await.is_err());
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use crate::api::HoleError;
+use crate::error;
+use crate::frb_generated::StreamSink;
+use crate::ticket::ShareTicket;
+use anyhow::{Context, Result};
+use futures::StreamExt;
+use iroh::discovery::static_provider::StaticProvider;
+use iroh::endpoint::Connection;
+use iroh::{Endpoint, EndpointAddr, EndpointId};
+use iroh_blobs::api::downloader::{DownloadOptions, DownloadProgressItem, Downloader, SplitStrategy};
+use iroh_blobs::api::remote::GetProgressItem;
+use iroh_blobs::hashseq::HashSeq;
+use iroh_blobs::protocol::{ChunkRanges, ChunkRangesExt, GetRequest};
+use iroh_blobs::store::fs::FsStore;
+use iroh_blobs::ticket::BlobTicket;
+use iroh_blobs::Hash;
+use std::future::Future;
+use std::str::FromStr;
+use std::time::Duration;
+
+// Don't split blobs into segments smaller than this many 1 KiB chunks.
+const MIN_SEGMENT_CHUNKS: u64 = 1024;
+
+const DEFAULT_MAX_RETRIES: u32 = 5;
+const DEFAULT_RETRY_DELAY: Duration = Duration::from_secs(1);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub initial_delay: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: Option<u32>, delay_ms: Option<u64>) -> Self {
+        Self {
+            max_retries: max_retries.unwrap_or(DEFAULT_MAX_RETRIES),
+            initial_delay: delay_ms.map(Duration::from_millis).unwrap_or(DEFAULT_RETRY_DELAY),
+        }
+    }
+
+    fn delay(&self, attempt: u32) -> Duration {
+        self.initial_delay
+            .saturating_mul(1 << (attempt - 1).min(16))
+            .min(MAX_RETRY_DELAY)
+    }
+}
+
+/// Runs `attempt` until it succeeds or the retries are used up, backing off
+/// exponentially in between and reporting every retry as
+/// `RETRY:<attempt>:<max>:<delay_ms>:<error>`. Only failed connections and
+/// transfers are retried, other errors like a hash mismatch, a full disk or
+/// a sender refusing the request are returned right away.
+///
+/// Downloads resume from whatever is already in the store, so a retry only
+/// fetches what's missing.
+pub async fn with_retry<F, Fut>(policy: RetryPolicy, sink: &StreamSink<String>, mut attempt: F) -> Result<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    let mut retries = 0;
+    loop {
+        match attempt().await {
+            Ok(()) => return Ok(()),
+            Err(e) if retries < policy.max_retries && error::is_transient(&e) => {
+                retries += 1;
+                let delay = policy.delay(retries);
+                sink.add(format!(
+                    "RETRY:{}:{}:{}:{}",
+                    retries,
+                    policy.max_retries,
+                    delay.as_millis(),
+                    e
+                ))
+                .ok();
+                tokio::time::sleep(delay).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+pub struct Providers {
+    /// The ticket the download started from.
+    pub ticket: BlobTicket,
+    /// The ticket's node first, then the extra sources.
+    pub ids: Vec<EndpointId>,
+}
+
+/// Collects the providers for a ticket: its own node first, then every extra
+/// source, which is either a ticket for the same content or a bare NodeId.
+///
+/// Addresses from tickets go into `discovery` so the downloader can dial them.
+pub fn collect_providers(
+    ticket: &BlobTicket,
+    sources: &[String],
+    discovery: &StaticProvider,
+) -> Result<Providers> {
+    discovery.add_endpoint_info(ticket.addr().clone());
+    let mut providers = vec![ticket.addr().id];
+    for source in sources.iter().map(|s| s.trim()).filter(|s| !s.is_empty()) {
+        let id = if let Ok(ShareTicket { blob: other, .. }) = ShareTicket::from_str(source) {
+            if other.hash() != ticket.hash() {
+                return Err(HoleError::InvalidInput(format!("Source is for different content: {}", other.hash())).into());
+            }
+            discovery.add_endpoint_info(other.addr().clone());
+            other.addr().id
+        } else {
+            EndpointId::from_str(source).context(HoleError::InvalidInput("Invalid source".into()))?
+        };
+        if !providers.contains(&id) {
+            providers.push(id);
+        }
+    }
+    Ok(Providers {
+        ticket: ticket.clone(),
+        ids: providers,
+    })
+}
+
+/// Fetches `request` from the providers, re-dialing and resuming on failure.
+///
+/// With a single provider everything comes in one request on one connection,
+/// reporting `PROGRESS:<bytes>:<total>` if the total is known. With more,
+/// the download is spread over all of them.
+pub async fn fetch(
+    store: &FsStore,
+    endpoint: &Endpoint,
+    providers: &Providers,
+    request: GetRequest,
+    retry: RetryPolicy,
+    total: Option<u64>,
+    sink: &StreamSink<String>,
+) -> Result<()> {
+    let Providers { ticket, ids } = providers;
+    let mut attempt = 0;
+    with_retry(retry, sink, || {
+        attempt += 1;
+        // Retries dial by NodeId only, so discovery can find the sender if its address changed
+        let addr = if attempt == 1 { ticket.addr().clone() } else { EndpointAddr::from(ticket.addr().id) };
+        let request = request.clone();
+        async move {
+            if ids.len() == 1 {
+                sink.add("Connecting...".to_string()).ok();
+                let connection = endpoint.connect(addr, iroh_blobs::protocol::ALPN).await?;
+                sink.add("Downloading...".to_string()).ok();
+                fetch_missing(store, connection, request, total, sink).await
+            } else {
+                sink.add(format!("Downloading from {} providers...", ids.len())).ok();
+                fetch_from_providers(store, endpoint, request, ids.clone(), sink).await
+            }
+        }
+    })
+    .await
+}
+
+// Asks for whatever of the request isn't in the store yet.
+async fn fetch_missing(
+    store: &FsStore,
+    connection: Connection,
+    request: GetRequest,
+    total: Option<u64>,
+    sink: &StreamSink<String>,
+) -> Result<()> {
+    let local = store.remote().local_for_request(request).await?;
+    if local.is_complete() {
+        return Ok(());
+    }
+    let have = local.local_bytes();
+    let mut last_percent = 0;
+    let mut stream = store.remote().execute_get(connection, local.missing()).stream();
+    while let Some(item) = stream.next().await {
+        match item {
+            GetProgressItem::Progress(bytes) => {
+                let Some(total) = total else { continue };
+                // Encrypted content is a bit larger than the total
+                let bytes = (have + bytes).min(total);
+                let percent = (bytes * 100).checked_div(total).unwrap_or(100);
+                if percent > last_percent {
+                    last_percent = percent;
+                    sink.add(format!("PROGRESS:{}:{}", bytes, total)).ok();
+                }
+            }
+            GetProgressItem::Done(_) => return Ok(()),
+            GetProgressItem::Error(e) => return Err(e.into()),
+        }
+    }
+    Err(HoleError::PeerUnreachable("Download ended early".into()).into())
+}
+
+/// Downloads a blob or the requested children of a hash sequence from
+/// several providers at once.
+///
+/// Large blobs are split into chunk ranges and every range starts at a
+/// different provider. When a provider fails, the range moves on to the next
+/// one and only asks for what is still missing.
+pub async fn fetch_from_providers(
+    store: &FsStore,
+    endpoint: &Endpoint,
+    request: GetRequest,
+    providers: Vec<EndpointId>,
+    sink: &StreamSink<String>,
+) -> Result<()> {
+    let downloader = store.downloader(endpoint);
+    let hash = request.hash;
+    let blobs = if request.ranges.is_blob() {
+        vec![hash]
+    } else {
+        let root = GetRequest::builder().root(ChunkRanges::all()).build(hash);
+        run(&downloader, root, providers.clone(), sink).await?;
+        let seq_bytes = store.get_bytes(hash).await?;
+        let seq = HashSeq::try_from(seq_bytes)?;
+        let mut blobs = Vec::new();
+        for (offset, _) in request.ranges.iter_non_empty_infinite() {
+            if offset == 0 {
+                continue;
+            }
+            let Some(blob) = seq.get((offset - 1) as usize) else { break };
+            blobs.push(blob);
+        }
+        blobs
+    };
+    for blob in blobs {
+        fetch_blob(store, &downloader, blob, &providers, sink).await?;
+    }
+    Ok(())
+}
+
+async fn fetch_blob(
+    store: &FsStore,
+    downloader: &Downloader,
+    hash: Hash,
+    providers: &[EndpointId],
+    sink: &StreamSink<String>,
+) -> Result<()> {
+    // The last chunk proves the size, which we need to split the blob.
+    let request = GetRequest::blob_ranges(hash, ChunkRanges::last_chunk());
+    run(downloader, request, providers.to_vec(), sink).await?;
+    let size = store
+        .observe(hash)
+        .await?
+        .validated_size()
+        .context("Blob size unknown")?;
+
+    let chunks = size.div_ceil(1024);
+    let segments = (providers.len() as u64)
+        .min(chunks / MIN_SEGMENT_CHUNKS)
+        .max(1);
+    let per_segment = chunks.div_ceil(segments);
+    let downloads = (0..segments).map(|i| {
+        let start = i * per_segment;
+        let end = ((i + 1) * per_segment).min(chunks);
+        let request = GetRequest::blob_ranges(hash, ChunkRanges::chunks(start..end));
+        let mut order = providers.to_vec();
+        order.rotate_left(i as usize % providers.len());
+        run(downloader, request, order, sink)
+    });
+    for res in futures::future::join_all(downloads).await {
+        res?;
+    }
+    Ok(())
+}
+
+async fn run(
+    downloader: &Downloader,
+    request: GetRequest,
+    providers: Vec<EndpointId>,
+    sink: &StreamSink<String>,
+) -> Result<()> {
+    let progress = downloader.download_with_opts(DownloadOptions::new(
+        request,
+        providers,
+        SplitStrategy::None,
+    ));
+    let mut stream = progress.stream().await?;
+    while let Some(item) = stream.next().await {
+        match item {
+            DownloadProgressItem::TryProvider { id, .. } => {
+                sink.add(format!("TRY_PROVIDER:{}", id)).ok();
+            }
+            DownloadProgressItem::ProviderFailed { id, .. } => {
+                sink.add(format!("PROVIDER_FAILED:{}", id)).ok();
+            }
+            DownloadProgressItem::Error(e) => return Err(e),
+            DownloadProgressItem::DownloadError => return Err(HoleError::PeerUnreachable("All providers failed".into()).into()),
+            _ => {}
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,95 @@
+// Turns internal errors into `HoleError`s for the app, by what they were
+// tagged with where they arose, or else by their causes.
+
+use crate::api::HoleError;
+use crate::frb_generated::StreamSink;
+use iroh::endpoint::{ConnectError, ConnectWithOptsError, ConnectionError};
+use iroh_blobs::get::fsm::DecodeError;
+use iroh_blobs::get::GetError;
+use iroh_blobs::protocol::ERR_PERMISSION;
+use std::fmt;
+use std::io;
+
+impl fmt::Display for HoleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message())
+    }
+}
+
+impl std::error::Error for HoleError {}
+
+impl From<anyhow::Error> for HoleError {
+    fn from(e: anyhow::Error) -> Self {
+        kind(&e)
+    }
+}
+
+/// The `HoleError` an error becomes, without taking it.
+pub fn kind(e: &anyhow::Error) -> HoleError {
+    if let Some(e) = e.downcast_ref::<HoleError>() {
+        return e.clone();
+    }
+    let message = e.to_string();
+    for cause in e.chain() {
+        if let Some(kind) = classify(cause, &message) {
+            return kind;
+        }
+    }
+    HoleError::Internal(message)
+}
+
+/// Whether trying again may help: the connection or transfer failed, rather
+/// than the data, the disk or the sender's consent.
+pub fn is_transient(e: &anyhow::Error) -> bool {
+    matches!(kind(e), HoleError::PeerUnreachable(_) | HoleError::Timeout(_))
+}
+
+fn classify(cause: &(dyn std::error::Error + 'static), message: &str) -> Option<HoleError> {
+    let message = message.to_string();
+    if let Some(e) = cause.downcast_ref::<io::Error>() {
+        if let Some(e) = e.get_ref().and_then(|e| e.downcast_ref::<HoleError>()) {
+            return Some(e.clone());
+        }
+        return match e.kind() {
+            io::ErrorKind::StorageFull | io::ErrorKind::QuotaExceeded => Some(HoleError::DiskFull(message)),
+            io::ErrorKind::PermissionDenied | io::ErrorKind::ReadOnlyFilesystem => Some(HoleError::PermissionDenied(message)),
+            io::ErrorKind::TimedOut => Some(HoleError::Timeout(message)),
+            io::ErrorKind::NotFound => Some(HoleError::NotFound(message)),
+            _ => None,
+        };
+    }
+    if cause.is::<tokio::time::error::Elapsed>() {
+        return Some(HoleError::Timeout(message));
+    }
+    if let Some(e) = cause.downcast_ref::<ConnectionError>() {
+        return Some(match e {
+            ConnectionError::TimedOut => HoleError::Timeout(message),
+            ConnectionError::ApplicationClosed(close) if close.error_code == ERR_PERMISSION => HoleError::AccessDenied(message),
+            _ => HoleError::PeerUnreachable(message),
+        });
+    }
+    if cause.is::<ConnectError>() || cause.is::<ConnectWithOptsError>() {
+        return Some(HoleError::PeerUnreachable(message));
+    }
+    let e = cause.downcast_ref::<GetError>()?;
+    if e.iroh_error_code() == Some(ERR_PERMISSION) {
+        return Some(HoleError::AccessDenied(message));
+    }
+    // Local failures are classified by their causes further down
+    match e {
+        GetError::Decode {
+            source: DecodeError::ParentHashMismatch { .. } | DecodeError::LeafHashMismatch { .. },
+            ..
+        } => Some(HoleError::HashMismatch(message)),
+        GetError::LocalFailure { .. } | GetError::BadRequest { .. } => None,
+        _ => Some(HoleError::PeerUnreachable(message)),
+    }
+}
+
+/// Emits `ERROR_CODE:<code>`, then `Error: <message>`, and returns the error.
+pub fn report(sink: &StreamSink<String>, e: impl Into<HoleError>) -> HoleError {
+    let e = e.into();
+    sink.add(format!("ERROR_CODE:{}", e.code())).ok();
+    sink.add("Error: ".to_string() + &e.message()).ok();
+    e
+}
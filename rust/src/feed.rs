@@ -0,0 +1,77 @@
+// Byte streams between Dart and the store, for content that never exists
+// as a file, e.g. from a share intent or into MediaStore.
+
+use crate::api::HoleError;
+use crate::frb_generated::StreamSink;
+use anyhow::Result;
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use rand::RngCore;
+use std::collections::HashMap;
+use std::io;
+use std::pin::Pin;
+use std::sync::{LazyLock, Mutex};
+use std::task::{Context, Poll};
+use tokio::io::AsyncWrite;
+use tokio::sync::mpsc;
+
+// How many chunks may wait for the import before `push` blocks.
+const BUFFERED_CHUNKS: usize = 16;
+
+static SENDERS: LazyLock<Mutex<HashMap<String, mpsc::Sender<Vec<u8>>>>> = LazyLock::new(Default::default);
+static RECEIVERS: LazyLock<Mutex<HashMap<String, mpsc::Receiver<Vec<u8>>>>> = LazyLock::new(Default::default);
+
+/// A random id for a feed or anything else that needs one.
+pub fn create_id() -> String {
+    let mut id = [0u8; 8];
+    rand::rng().fill_bytes(&mut id);
+    hex::encode(id)
+}
+
+/// Opens a new feed and returns its id.
+pub fn create() -> String {
+    let id = create_id();
+    let (tx, rx) = mpsc::channel(BUFFERED_CHUNKS);
+    SENDERS.lock().unwrap().insert(id.clone(), tx);
+    RECEIVERS.lock().unwrap().insert(id.clone(), rx);
+    id
+}
+
+/// Appends a chunk, waiting while the import is behind.
+pub async fn push(id: &str, chunk: Vec<u8>) -> Result<()> {
+    let tx = SENDERS.lock().unwrap().get(id).cloned().ok_or_else(|| HoleError::NotFound("No such stream".into()))?;
+    tx.send(chunk).await.map_err(|_| HoleError::Cancelled("Stream was closed".into()).into())
+}
+
+/// Marks the end of the content.
+pub fn finish(id: &str) -> Result<()> {
+    SENDERS.lock().unwrap().remove(id).map(|_| ()).ok_or_else(|| HoleError::NotFound("No such stream".into()).into())
+}
+
+/// Takes the chunks of a feed, which end once the feed is finished.
+pub fn take(id: &str) -> Result<impl Stream<Item = io::Result<Bytes>> + Send + Sync + 'static> {
+    let mut rx = RECEIVERS.lock().unwrap().remove(id).ok_or_else(|| HoleError::NotFound("No such stream".into()))?;
+    let chunks = futures::stream::poll_fn(move |cx| rx.poll_recv(cx));
+    Ok(chunks.map(|chunk| Ok(Bytes::from(chunk))))
+}
+
+/// Writes into a Dart stream, one chunk per write. Wrap it in a `BufWriter`
+/// to get fewer, larger chunks.
+pub struct SinkWriter(pub StreamSink<Vec<u8>>);
+
+impl AsyncWrite for SinkWriter {
+    fn poll_write(self: Pin<&mut Self>, _: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        match self.0.add(buf.to_vec()) {
+            Ok(()) => Poll::Ready(Ok(buf.len())),
+            Err(_) => Poll::Ready(Err(io::Error::new(io::ErrorKind::BrokenPipe, HoleError::Cancelled("Stream was closed".into())))),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
@@ -0,0 +1,213 @@
+// Magic-number detection of common file types.
+
+use std::path::Path;
+use tokio::io::AsyncReadExt;
+
+/// How many leading bytes `detect` wants to see. TAR keeps its magic at
+/// offset 257 and ZIP based formats need a look at the first entries.
+pub const HEADER_LEN: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileType {
+    pub ext: &'static str,
+    pub mime: &'static str,
+}
+
+impl FileType {
+    pub const BINARY: FileType = FileType::new("bin", "application/octet-stream");
+
+    const fn new(ext: &'static str, mime: &'static str) -> Self {
+        Self { ext, mime }
+    }
+}
+
+/// Detects the file type from the start of a file, see `HEADER_LEN`.
+pub fn detect(header: &[u8]) -> Option<FileType> {
+    let h = header;
+    let t = FileType::new;
+    let found = if h.starts_with(b"\xff\xd8\xff") {
+        t("jpg", "image/jpeg")
+    } else if h.starts_with(b"\x89PNG\r\n\x1a\n") {
+        t("png", "image/png")
+    } else if h.starts_with(b"GIF87a") || h.starts_with(b"GIF89a") {
+        t("gif", "image/gif")
+    } else if h.len() >= 12 && &h[0..4] == b"RIFF" && &h[8..12] == b"WEBP" {
+        t("webp", "image/webp")
+    } else if h.len() >= 12 && &h[0..4] == b"RIFF" && &h[8..12] == b"WAVE" {
+        t("wav", "audio/wav")
+    } else if h.starts_with(b"%PDF") {
+        t("pdf", "application/pdf")
+    } else if h.len() >= 12 && &h[4..8] == b"ftyp" {
+        detect_iso_bmff(h)
+    } else if h.starts_with(b"PK\x03\x04") {
+        detect_zip(h)
+    } else if h.starts_with(b"ID3") || (h.len() >= 2 && h[0] == 0xff && h[1] & 0xe0 == 0xe0 && h[1] & 0x06 != 0) {
+        // An ID3 tag or the sync word of an MPEG audio frame (layer bits set)
+        t("mp3", "audio/mpeg")
+    } else if h.starts_with(b"OggS") {
+        t("ogg", "audio/ogg")
+    } else if h.starts_with(b"fLaC") {
+        t("flac", "audio/flac")
+    } else if h.starts_with(b"\x1f\x8b") {
+        t("gz", "application/gzip")
+    } else if h.starts_with(b"7z\xbc\xaf\x27\x1c") {
+        t("7z", "application/x-7z-compressed")
+    } else if h.len() >= 262 && &h[257..262] == b"ustar" {
+        t("tar", "application/x-tar")
+    } else if h.starts_with(b"SQLite format 3\0") {
+        t("sqlite", "application/vnd.sqlite3")
+    } else if is_text(h) {
+        t("txt", "text/plain")
+    } else {
+        return None;
+    };
+    Some(found)
+}
+
+/// Looks up the type for a file extension, without the dot.
+pub fn from_extension(ext: &str) -> Option<FileType> {
+    let t = FileType::new;
+    let found = match ext.to_ascii_lowercase().as_str() {
+        "jpg" | "jpeg" => t("jpg", "image/jpeg"),
+        "png" => t("png", "image/png"),
+        "gif" => t("gif", "image/gif"),
+        "webp" => t("webp", "image/webp"),
+        "heic" | "heif" => t("heic", "image/heic"),
+        "avif" => t("avif", "image/avif"),
+        "svg" => t("svg", "image/svg+xml"),
+        "mp4" | "m4v" => t("mp4", "video/mp4"),
+        "mov" => t("mov", "video/quicktime"),
+        "mkv" => t("mkv", "video/x-matroska"),
+        "webm" => t("webm", "video/webm"),
+        "mp3" => t("mp3", "audio/mpeg"),
+        "m4a" => t("m4a", "audio/mp4"),
+        "ogg" | "oga" => t("ogg", "audio/ogg"),
+        "flac" => t("flac", "audio/flac"),
+        "wav" => t("wav", "audio/wav"),
+        "pdf" => t("pdf", "application/pdf"),
+        "zip" => t("zip", "application/zip"),
+        "docx" => t("docx", "application/vnd.openxmlformats-officedocument.wordprocessingml.document"),
+        "xlsx" => t("xlsx", "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"),
+        "pptx" => t("pptx", "application/vnd.openxmlformats-officedocument.presentationml.presentation"),
+        "apk" => t("apk", "application/vnd.android.package-archive"),
+        "gz" | "tgz" => t("gz", "application/gzip"),
+        "tar" => t("tar", "application/x-tar"),
+        "7z" => t("7z", "application/x-7z-compressed"),
+        "sqlite" | "db" => t("sqlite", "application/vnd.sqlite3"),
+        "txt" | "log" => t("txt", "text/plain"),
+        "md" => t("md", "text/markdown"),
+        "csv" => t("csv", "text/csv"),
+        "html" | "htm" => t("html", "text/html"),
+        "json" => t("json", "application/json"),
+        "xml" => t("xml", "application/xml"),
+        _ => return None,
+    };
+    Some(found)
+}
+
+/// Detects the type of a file by its content, refined by its extension.
+pub async fn detect_file(path: &Path) -> FileType {
+    let mut header = Vec::new();
+    if let Ok(file) = tokio::fs::File::open(path).await {
+        file.take(HEADER_LEN as u64).read_to_end(&mut header).await.ok();
+    }
+    detect_named(&path.to_string_lossy(), &header)
+}
+
+/// Detects the type from a file name and the start of the content.
+///
+/// The content wins, except where it only tells the container: text could
+/// be CSV or JSON and a ZIP archive could be an EPUB or JAR.
+pub fn detect_named(name: &str, header: &[u8]) -> FileType {
+    let sniffed = detect(header);
+    let by_ext = Path::new(name).extension().and_then(|ext| from_extension(&ext.to_string_lossy()));
+    match (sniffed, by_ext) {
+        (Some(sniffed), Some(by_ext)) if matches!(sniffed.ext, "txt" | "zip") => by_ext,
+        (Some(sniffed), _) => sniffed,
+        (None, Some(by_ext)) => by_ext,
+        (None, None) => FileType::BINARY,
+    }
+}
+
+// MP4, MOV, M4A, HEIC and AVIF all start with an `ftyp` box listing the
+// major brand and the compatible ones.
+fn detect_iso_bmff(h: &[u8]) -> FileType {
+    let t = FileType::new;
+    let box_len = u32::from_be_bytes([h[0], h[1], h[2], h[3]]) as usize;
+    let end = box_len.clamp(12, h.len());
+    let major = &h[8..12];
+    let compatible: Vec<&[u8]> = h[12.min(end)..end].chunks_exact(4).skip(1).collect();
+    let has = |brand: &[u8]| major == brand || compatible.contains(&brand);
+    match major {
+        b"qt  " => t("mov", "video/quicktime"),
+        b"M4A " | b"M4B " => t("m4a", "audio/mp4"),
+        b"heic" | b"heix" | b"heim" | b"heis" | b"hevc" | b"hevx" => t("heic", "image/heic"),
+        b"avif" | b"avis" => t("avif", "image/avif"),
+        b"mif1" | b"msf1" if has(b"avif") => t("avif", "image/avif"),
+        b"mif1" | b"msf1" => t("heic", "image/heic"),
+        _ => t("mp4", "video/mp4"),
+    }
+}
+
+// DOCX, XLSX, PPTX and APK are all ZIP archives, told apart by the names
+// of their first entries.
+fn detect_zip(h: &[u8]) -> FileType {
+    let t = FileType::new;
+    let contains = |needle: &[u8]| h.windows(needle.len()).any(|w| w == needle);
+    if contains(b"word/") {
+        t("docx", "application/vnd.openxmlformats-officedocument.wordprocessingml.document")
+    } else if contains(b"xl/") {
+        t("xlsx", "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet")
+    } else if contains(b"ppt/") {
+        t("pptx", "application/vnd.openxmlformats-officedocument.presentationml.presentation")
+    } else if contains(b"AndroidManifest.xml") || contains(b"classes.dex") {
+        t("apk", "application/vnd.android.package-archive")
+    } else {
+        t("zip", "application/zip")
+    }
+}
+
+// Valid UTF-8 without control characters other than whitespace. The header
+// may cut a multi-byte character in half, which is fine.
+fn is_text(h: &[u8]) -> bool {
+    if h.is_empty() {
+        return false;
+    }
+    let text = match std::str::from_utf8(h) {
+        Ok(text) => text,
+        Err(e) if e.error_len().is_none() => std::str::from_utf8(&h[..e.valid_up_to()]).unwrap_or_default(),
+        Err(_) => return false,
+    };
+    !text.is_empty() && !text.chars().any(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t' | '\x0c'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_magic() {
+        assert_eq!(detect(b"\x89PNG\r\n\x1a\n....").unwrap().ext, "png");
+        assert_eq!(detect(b"%PDF-1.7").unwrap().ext, "pdf");
+        assert_eq!(detect(b"\x00\x00\x00\x18ftypqt  \x00\x00\x00\x00").unwrap().ext, "mov");
+        assert_eq!(detect(b"\x00\x00\x00\x18ftypmif1\x00\x00\x00\x00mif1avif").unwrap().ext, "avif");
+        assert_eq!(detect(b"PK\x03\x04....word/document.xml").unwrap().ext, "docx");
+        assert_eq!(detect(b"hello\nworld").unwrap().ext, "txt");
+        assert_eq!(detect(b"\x00\x01\x02\x03"), None);
+        assert_eq!(detect(b""), None);
+    }
+
+    #[test]
+    fn text_cut_in_a_character() {
+        assert_eq!(detect("grüß".as_bytes()[..5].as_ref()).unwrap().ext, "txt");
+    }
+
+    #[test]
+    fn named() {
+        // The extension refines text and ZIP, the content wins otherwise
+        assert_eq!(detect_named("data.csv", b"a,b\n1,2").ext, "csv");
+        assert_eq!(detect_named("photo.txt", b"\xff\xd8\xff\xe0").ext, "jpg");
+        assert_eq!(detect_named("song.MP3", b"\x00\x01").ext, "mp3");
+        assert_eq!(detect_named("unknown", b"\x00\x01"), FileType::BINARY);
+    }
+}
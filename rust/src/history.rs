@@ -0,0 +1,113 @@
+// Past transfers, kept in `history.redb` in the data dir along with the
+// saved shares, see `shares`.
+
+use crate::api::{TransferDirection, TransferRecord};
+use anyhow::Result;
+use redb::{Database, ReadableTable, ReadableTableMetadata, TableDefinition};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Records by id as JSON, ids count up so the newest is last.
+const TRANSFERS: TableDefinition<u64, &[u8]> = TableDefinition::new("transfers");
+
+// A database can only be opened once per process, sends and receives share it.
+static DATABASES: LazyLock<Mutex<HashMap<PathBuf, Arc<Database>>>> = LazyLock::new(Default::default);
+
+pub fn now_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or_default()
+}
+
+/// Opens the database of the data dir, creating it and the transfers table.
+pub fn database(data_dir: &Path) -> Result<Arc<Database>> {
+    let path = data_dir.join("history.redb");
+    let mut databases = DATABASES.lock().unwrap();
+    if let Some(db) = databases.get(&path) {
+        return Ok(db.clone());
+    }
+    std::fs::create_dir_all(data_dir)?;
+    let db = Database::create(&path)?;
+    // Create the table, so reads never find it missing
+    let txn = db.begin_write()?;
+    txn.open_table(TRANSFERS)?;
+    txn.commit()?;
+    let db = Arc::new(db);
+    databases.insert(path, db.clone());
+    Ok(db)
+}
+
+/// Stores a record under a new id, which is returned.
+pub async fn add(data_dir: &Path, mut record: TransferRecord) -> Result<u64> {
+    let data_dir = data_dir.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let db = database(&data_dir)?;
+        let txn = db.begin_write()?;
+        let id = {
+            let mut table = txn.open_table(TRANSFERS)?;
+            let id = table.last()?.map(|(id, _)| id.value() + 1).unwrap_or(1);
+            record.id = id;
+            table.insert(id, serde_json::to_vec(&record)?.as_slice())?;
+            id
+        };
+        txn.commit()?;
+        Ok(id)
+    })
+    .await?
+}
+
+/// Lists records newest first, optionally of one direction only.
+pub async fn list(data_dir: &Path, direction: Option<TransferDirection>, offset: usize, limit: Option<usize>) -> Result<Vec<TransferRecord>> {
+    let data_dir = data_dir.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let db = database(&data_dir)?;
+        let table = db.begin_read()?.open_table(TRANSFERS)?;
+        let mut records = Vec::new();
+        for entry in table.iter()?.rev() {
+            let (_, value) = entry?;
+            // Skip what an older or newer build wrote differently
+            let Ok(record) = serde_json::from_slice::<TransferRecord>(value.value()) else {
+                continue;
+            };
+            if direction.is_none_or(|direction| record.direction == direction) {
+                records.push(record);
+            }
+            if limit.is_some_and(|limit| records.len() >= offset + limit) {
+                break;
+            }
+        }
+        Ok(records.into_iter().skip(offset).collect())
+    })
+    .await?
+}
+
+/// Deletes a record, returning whether it existed.
+pub async fn delete(data_dir: &Path, id: u64) -> Result<bool> {
+    let data_dir = data_dir.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let db = database(&data_dir)?;
+        let txn = db.begin_write()?;
+        let existed = txn.open_table(TRANSFERS)?.remove(id)?.is_some();
+        txn.commit()?;
+        Ok(existed)
+    })
+    .await?
+}
+
+/// Deletes all records, returning how many there were.
+pub async fn clear(data_dir: &Path) -> Result<u64> {
+    let data_dir = data_dir.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let db = database(&data_dir)?;
+        let txn = db.begin_write()?;
+        let count = {
+            let mut table = txn.open_table(TRANSFERS)?;
+            let count = table.len()?;
+            table.retain(|_, _| false)?;
+            count
+        };
+        txn.commit()?;
+        Ok(count)
+    })
+    .await?
+}
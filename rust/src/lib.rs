@@ -1,2 +1,15 @@
 pub mod api;
+mod crypto;
+mod download;
+mod error;
+mod feed;
+mod filetype;
 mod frb_generated;
+mod history;
+mod meta;
+mod path;
+mod provider;
+mod shares;
+mod ranged;
+mod store;
+mod ticket;
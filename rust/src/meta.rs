@@ -0,0 +1,66 @@
+use crate::api::HoleError;
+use crate::ticket::PROTOCOL_VERSION;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Details about the shared file beyond its name, stored as JSON in the
+/// third blob of the sequence. Older senders don't have it, older receivers
+/// don't look at it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ShareInfo {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime: Option<String>,
+    #[serde(default)]
+    pub kind: ContentKind,
+    /// Size of the content before encryption, so it's known without
+    /// fetching the file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    /// Set for shares of several files, the fields above then describe the
+    /// share as a whole.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub items: Vec<Item>,
+    /// The layout the share was sent in. Plain tickets don't tell, see
+    /// `ticket::version_for`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+}
+
+/// A file in a share of several. The first one is child 1 of the sequence
+/// like in single-file shares, so older receivers still get it, the others
+/// follow the info blob.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Item {
+    /// Path of the file relative to the folder all files of the share are
+    /// in, with `/` between folders.
+    pub name: String,
+    pub child: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentKind {
+    #[default]
+    File,
+    /// Handed to the app as a string instead of being saved.
+    Text,
+}
+
+impl ShareInfo {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("ShareInfo is always serializable")
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let info: Self = serde_json::from_slice(bytes).context(HoleError::MetadataInvalid("Invalid share info".into()))?;
+        if info.version.is_some_and(|version| version > PROTOCOL_VERSION) {
+            let message = "This share was sent by a newer version of the app, please update";
+            return Err(HoleError::UnsupportedVersion(message.into()).into());
+        }
+        Ok(info)
+    }
+}
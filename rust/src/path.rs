@@ -0,0 +1,86 @@
+use crate::frb_generated::StreamSink;
+use iroh::endpoint::ConnectionType;
+use iroh::{Endpoint, EndpointId, Watcher};
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+// How often to wait for address info of a peer we haven't heard from yet.
+const ADDR_POLL_INTERVAL: Duration = Duration::from_millis(200);
+const RTT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Reports how we're connected to a peer until dropped:
+///
+/// - `PATH:<peer>:direct:<ipv4|ipv6>:<addr>`
+/// - `PATH:<peer>:relay:<relay_url>`
+/// - `PATH:<peer>:mixed:<ipv4|ipv6>:<addr> <relay_url>`
+/// - `PATH_UPGRADED:<peer>:<addr>` when a relayed connection goes direct
+/// - `RTT:<peer>:<ms>` every few seconds
+pub struct PathReporter(JoinHandle<()>);
+
+impl PathReporter {
+    pub fn spawn(endpoint: Endpoint, peer: EndpointId, sink: StreamSink<String>) -> Self {
+        Self(tokio::spawn(report(endpoint, peer, sink)))
+    }
+}
+
+impl Drop for PathReporter {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+fn family(addr: &SocketAddr) -> &'static str {
+    if addr.is_ipv4() {
+        "ipv4"
+    } else {
+        "ipv6"
+    }
+}
+
+async fn report(endpoint: Endpoint, peer: EndpointId, sink: StreamSink<String>) {
+    // There is nothing to watch until the endpoint knows an address for the peer.
+    let mut watcher = loop {
+        if let Some(watcher) = endpoint.conn_type(peer) {
+            break watcher;
+        }
+        tokio::time::sleep(ADDR_POLL_INTERVAL).await;
+    };
+    let mut conn_type = watcher.get();
+    let mut relayed = false;
+    let mut rtt = tokio::time::interval(RTT_INTERVAL);
+    loop {
+        match &conn_type {
+            ConnectionType::Direct(addr) => {
+                if relayed {
+                    sink.add(format!("PATH_UPGRADED:{}:{}", peer, addr)).ok();
+                }
+                sink.add(format!("PATH:{}:direct:{}:{}", peer, family(addr), addr)).ok();
+                relayed = false;
+            }
+            ConnectionType::Relay(url) => {
+                sink.add(format!("PATH:{}:relay:{}", peer, url)).ok();
+                relayed = true;
+            }
+            ConnectionType::Mixed(addr, url) => {
+                sink.add(format!("PATH:{}:mixed:{}:{} {}", peer, family(addr), addr, url)).ok();
+                relayed = true;
+            }
+            ConnectionType::None => {}
+        }
+        // Wait for the path to change, reporting the latency in the meantime.
+        conn_type = loop {
+            tokio::select! {
+                res = watcher.updated() => match res {
+                    Ok(conn_type) => break conn_type,
+                    Err(_) => return,
+                },
+                _ = rtt.tick() => {
+                    if let Some(latency) = endpoint.latency(peer) {
+                        sink.add(format!("RTT:{}:{}", peer, latency.as_millis())).ok();
+                    }
+                }
+            }
+        };
+    }
+}
@@ -0,0 +1,436 @@
+use crate::api::{HoleError, TransferOutcome, TransferRecord};
+use crate::frb_generated::StreamSink;
+use crate::history;
+use crate::path::PathReporter;
+use crate::shares;
+use anyhow::{Context, Result};
+use iroh::endpoint::ConnectionType;
+use iroh::{Endpoint, EndpointId, Watcher};
+use iroh_blobs::provider::events::{
+    AbortReason, ConnectMode, EventMask, EventSender, ProviderMessage, RequestMode, RequestUpdate,
+};
+use iroh_blobs::protocol::{ChunkRanges, ChunkRangesExt, ChunkRangesSeq};
+use iroh_blobs::{BlobFormat, Hash, HashAndFormat};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{oneshot, Notify};
+
+// Unknown peers are denied if the user doesn't answer in time.
+const PROMPT_TIMEOUT: Duration = Duration::from_secs(60);
+
+// Connections waiting for the user to approve or deny the peer, by NodeId.
+static PENDING_PEERS: LazyLock<Mutex<HashMap<EndpointId, Vec<oneshot::Sender<bool>>>>> =
+    LazyLock::new(Default::default);
+
+pub fn respond_to_peer(node_id: &str, approve: bool) -> Result<()> {
+    let node_id = EndpointId::from_str(node_id).context(HoleError::InvalidInput("Invalid NodeId".into()))?;
+    let waiting = PENDING_PEERS.lock().unwrap().remove(&node_id).unwrap_or_default();
+    if waiting.is_empty() {
+        return Err(HoleError::NotFound("No pending request from this peer".into()).into());
+    }
+    for tx in waiting {
+        tx.send(approve).ok();
+    }
+    Ok(())
+}
+
+pub struct AccessPolicy {
+    allowed: Mutex<HashSet<EndpointId>>,
+    prompt: bool,
+}
+
+impl AccessPolicy {
+    pub fn new(allowlist: &[String], prompt: bool) -> Result<Self> {
+        let allowed = allowlist
+            .iter()
+            .map(|id| EndpointId::from_str(id.trim()).context(HoleError::InvalidInput("Invalid NodeId in allowlist".into())))
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            allowed: Mutex::new(allowed),
+            prompt,
+        })
+    }
+
+    /// An open policy serves everyone without asking.
+    pub fn is_open(&self) -> bool {
+        !self.prompt && self.allowed.lock().unwrap().is_empty()
+    }
+
+    async fn check(&self, node_id: EndpointId, sink: &StreamSink<String>) -> bool {
+        if self.allowed.lock().unwrap().contains(&node_id) {
+            return true;
+        }
+        if !self.prompt {
+            return false;
+        }
+
+        let (tx, rx) = oneshot::channel();
+        PENDING_PEERS.lock().unwrap().entry(node_id).or_default().push(tx);
+        sink.add(format!("PEER_REQUEST:{}", node_id)).ok();
+        let approved = matches!(tokio::time::timeout(PROMPT_TIMEOUT, rx).await, Ok(Ok(true)));
+        PENDING_PEERS.lock().unwrap().remove(&node_id);
+
+        // Remember the answer for the rest of the share, so reconnects don't prompt again.
+        if approved {
+            self.allowed.lock().unwrap().insert(node_id);
+        }
+        approved
+    }
+}
+
+pub struct Share {
+    /// What the ticket points to.
+    pub root: HashAndFormat,
+    /// Every blob this share is allowed to serve.
+    pub hashes: HashSet<Hash>,
+    /// The blobs whose completed transfer counts as a download.
+    pub files: HashSet<Hash>,
+    pub max_downloads: Option<u32>,
+    /// Notified once `max_downloads` downloads have completed.
+    pub limit_reached: Arc<Notify>,
+    /// Where the history is kept.
+    pub data_dir: PathBuf,
+    /// What gets recorded for every download, completed with the peer and
+    /// the outcome.
+    pub record: TransferRecord,
+    /// Downloads completed before, when serving a saved share again.
+    pub downloaded: u32,
+    /// Id of the saved share, whose download count is kept up to date.
+    pub saved_id: Option<String>,
+}
+
+impl Share {
+    async fn record(&self, peer: &str, started_at_ms: u64, started: Instant, error: Option<String>) {
+        let mut record = self.record.clone();
+        record.peer = peer.to_string();
+        record.started_at_ms = started_at_ms;
+        record.duration_ms = started.elapsed().as_millis() as u64;
+        record.outcome = if error.is_some() { TransferOutcome::Failed } else { TransferOutcome::Completed };
+        record.error = error;
+        history::add(&self.data_dir, record).await.ok();
+    }
+}
+
+impl Share {
+    fn exhausted(&self, downloads: &Downloads) -> bool {
+        self.max_downloads
+            .is_some_and(|max| downloads.count.load(Ordering::SeqCst) >= max)
+    }
+
+    // Requests with child ranges read the requested blob as a sequence and
+    // serve every hash in it, so only the root sequence may have children.
+    fn allows(&self, hash: Hash, ranges: &ChunkRangesSeq) -> bool {
+        let is_root_seq = hash == self.root.hash && self.root.format == BlobFormat::HashSeq;
+        self.hashes.contains(&hash) && (ranges.is_blob() || is_root_seq)
+    }
+}
+
+struct Downloads {
+    count: Arc<AtomicU32>,
+    // A connection counts once, however many requests it takes, e.g. for
+    // ranged reads or parallel segments. Ids are per endpoint.
+    connections: Mutex<HashSet<u64>>,
+    // Chunks of the shared files served so far, by peer and file. Kept
+    // across connections, so a download resumed on a new one still counts.
+    served: Mutex<HashMap<(Option<EndpointId>, Hash), ChunkRanges>>,
+}
+
+impl Downloads {
+    // Whether the file has every chunk served to the peer once these ranges
+    // are, and so was downloaded rather than probed or skimmed.
+    fn completes(&self, peer: Option<EndpointId>, hash: Hash, ranges: &ChunkRanges, size: u64) -> bool {
+        let served = self.served.lock().unwrap().get(&(peer, hash)).cloned().unwrap_or_default();
+        (served | ranges.clone()).is_superset(&all_chunks(size))
+    }
+
+    // Adds the ranges of the files served on a connection. Returns whether
+    // that makes a new download: a file served whole to the peer, over this
+    // connection and earlier ones. Its chunks then count towards the next.
+    fn serve(&self, connection_id: u64, peer: Option<EndpointId>, files: &[(Hash, ChunkRanges, u64)]) -> bool {
+        let mut served = self.served.lock().unwrap();
+        let mut complete = false;
+        for (hash, ranges, size) in files {
+            let after = served.remove(&(peer, *hash)).unwrap_or_default() | ranges.clone();
+            if after.is_superset(&all_chunks(*size)) {
+                complete = true;
+            } else {
+                served.insert((peer, *hash), after);
+            }
+        }
+        complete && self.connections.lock().unwrap().insert(connection_id)
+    }
+}
+
+fn all_chunks(size: u64) -> ChunkRanges {
+    ChunkRanges::chunks(0..size.saturating_sub(1) / 1024 + 1)
+}
+
+// The ranges the request asks for of the blob at `index`.
+fn requested(ranges: &ChunkRangesSeq, index: u64) -> ChunkRanges {
+    ranges.iter_infinite().nth(index as usize).cloned().unwrap_or_default()
+}
+
+fn peer_name(peers: &HashMap<u64, EndpointId>, connection_id: u64) -> String {
+    peers
+        .get(&connection_id)
+        .map(|id| id.to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn conn_kind(endpoint: &Endpoint, node_id: EndpointId) -> &'static str {
+    match endpoint.conn_type(node_id).map(|mut t| t.get()) {
+        Some(ConnectionType::Direct(_)) => "direct",
+        Some(ConnectionType::Relay(_)) => "relay",
+        Some(ConnectionType::Mixed(..)) => "mixed",
+        _ => "unknown",
+    }
+}
+
+// Reports the transfer updates of an accepted request and counts it as a
+// download once any of the shared files was served whole to the peer.
+fn track_request(
+    mut updates: irpc::channel::mpsc::Receiver<RequestUpdate>,
+    ranges: ChunkRangesSeq,
+    connection_id: u64,
+    peer_id: Option<EndpointId>,
+    share: Arc<Share>,
+    downloads: Arc<Downloads>,
+    sink: StreamSink<String>,
+) {
+    let peer = peer_id.map(|id| id.to_string()).unwrap_or_else(|| "unknown".to_string());
+    tokio::spawn(async move {
+        let (started_at_ms, started) = (history::now_ms(), Instant::now());
+        let mut has_file = false;
+        let mut files = Vec::new();
+        let mut current = None;
+        let mut last_percent = 0;
+        while let Ok(Some(update)) = updates.recv().await {
+            match update {
+                RequestUpdate::Started(started) => {
+                    if share.files.contains(&started.hash) {
+                        let requested = requested(&ranges, started.index);
+                        has_file |= downloads.completes(peer_id, started.hash, &requested, started.size);
+                        files.push((started.hash, requested, started.size));
+                    }
+                    sink.add(format!("TRANSFER_STARTED:{}:{}:{}", peer, started.hash, started.size)).ok();
+                    current = Some((started.hash, started.size));
+                    last_percent = 0;
+                }
+                RequestUpdate::Progress(progress) => {
+                    // Progress arrives per 16 KiB chunk, only report whole percents.
+                    let Some((hash, size)) = current else { continue };
+                    let percent = (progress.end_offset * 100).checked_div(size).unwrap_or(100);
+                    if percent > last_percent {
+                        last_percent = percent;
+                        sink.add(format!("TRANSFER_PROGRESS:{}:{}:{}:{}", peer, hash, progress.end_offset, size)).ok();
+                    }
+                }
+                RequestUpdate::Completed(completed) => {
+                    sink.add(format!("TRANSFER_COMPLETED:{}:{}", peer, completed.stats.payload_bytes_sent)).ok();
+                    if downloads.serve(connection_id, peer_id, &files) {
+                        sink.add(format!("DOWNLOAD_COMPLETED:{}", peer)).ok();
+                        let count = downloads.count.fetch_add(1, Ordering::SeqCst) + 1;
+                        if share.max_downloads.is_some_and(|max| count >= max) {
+                            share.limit_reached.notify_one();
+                        }
+                        share.record(&peer, started_at_ms, started, None).await;
+                        if let Some(id) = &share.saved_id {
+                            shares::update(&share.data_dir, id, |saved| saved.downloads += 1).await.ok();
+                        }
+                    }
+                }
+                RequestUpdate::Aborted(aborted) => {
+                    sink.add(format!("TRANSFER_ABORTED:{}:{}", peer, aborted.stats.payload_bytes_sent)).ok();
+                    if has_file && !downloads.connections.lock().unwrap().contains(&connection_id) {
+                        let error = Some("Transfer aborted".to_string());
+                        share.record(&peer, started_at_ms, started, error).await;
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Serves a share across endpoints: the policy and the download count are
+/// kept when serving is paused and resumed on a new endpoint.
+pub struct Provider {
+    policy: Arc<AccessPolicy>,
+    share: Arc<Share>,
+    count: Arc<AtomicU32>,
+}
+
+impl Provider {
+    pub fn new(policy: AccessPolicy, share: Share) -> Self {
+        Self {
+            count: Arc::new(AtomicU32::new(share.downloaded)),
+            policy: Arc::new(policy),
+            share: Arc::new(share),
+        }
+    }
+
+    pub fn spawn_event_handler(&self, endpoint: Endpoint, sink: StreamSink<String>) -> EventSender {
+        spawn_event_handler(endpoint, self.policy.clone(), self.share.clone(), self.count.clone(), sink)
+    }
+}
+
+fn spawn_event_handler(
+    endpoint: Endpoint,
+    policy: Arc<AccessPolicy>,
+    share: Arc<Share>,
+    count: Arc<AtomicU32>,
+    sink: StreamSink<String>,
+) -> EventSender {
+    // Note that the provider checks the `get` mode for every request kind,
+    // including push and observe, so all of them end up in this handler.
+    let mask = EventMask {
+        connected: ConnectMode::Intercept,
+        get: RequestMode::InterceptLog,
+        ..EventMask::DEFAULT
+    };
+    let (events, mut rx) = EventSender::channel(32, mask);
+    let downloads = Arc::new(Downloads {
+        count,
+        connections: Default::default(),
+        served: Default::default(),
+    });
+    // Path reports run for as long as their connection is open.
+    let reporters: Arc<Mutex<HashMap<u64, PathReporter>>> = Default::default();
+    tokio::spawn(async move {
+        let mut peers = HashMap::new();
+        while let Some(msg) = rx.recv().await {
+            match msg {
+                ProviderMessage::ClientConnected(msg) => {
+                    if let Some(node_id) = msg.inner.endpoint_id {
+                        peers.insert(msg.inner.connection_id, node_id);
+                    }
+                    if policy.is_open() {
+                        if let Some(node_id) = msg.inner.endpoint_id {
+                            let kind = conn_kind(&endpoint, node_id);
+                            sink.add(format!("PEER_CONNECTED:{}:{}", node_id, kind)).ok();
+                            let reporter = PathReporter::spawn(endpoint.clone(), node_id, sink.clone());
+                            reporters.lock().unwrap().insert(msg.inner.connection_id, reporter);
+                        }
+                        msg.tx.send(Ok(())).await.ok();
+                        continue;
+                    }
+                    let endpoint = endpoint.clone();
+                    let policy = policy.clone();
+                    let reporters = reporters.clone();
+                    let sink = sink.clone();
+                    // Waiting on the user must not hold up other connections.
+                    tokio::spawn(async move {
+                        let allowed = match msg.inner.endpoint_id {
+                            Some(node_id) => {
+                                let allowed = policy.check(node_id, &sink).await;
+                                let verdict = if allowed { "PEER_APPROVED:" } else { "PEER_DENIED:" };
+                                sink.add(verdict.to_string() + &node_id.to_string()).ok();
+                                if allowed {
+                                    let kind = conn_kind(&endpoint, node_id);
+                                    sink.add(format!("PEER_CONNECTED:{}:{}", node_id, kind)).ok();
+                                    let reporter = PathReporter::spawn(endpoint.clone(), node_id, sink.clone());
+                                    reporters.lock().unwrap().insert(msg.inner.connection_id, reporter);
+                                }
+                                allowed
+                            }
+                            None => false,
+                        };
+                        let res = if allowed { Ok(()) } else { Err(AbortReason::Permission) };
+                        msg.tx.send(res).await.ok();
+                    });
+                }
+                ProviderMessage::ConnectionClosed(msg) => {
+                    reporters.lock().unwrap().remove(&msg.inner.connection_id);
+                    if let Some(node_id) = peers.remove(&msg.inner.connection_id) {
+                        sink.add(format!("PEER_DISCONNECTED:{}", node_id)).ok();
+                    }
+                }
+                // Only the blobs of this share may be served, everything else
+                // in the store stays private.
+                ProviderMessage::GetRequestReceived(msg) => {
+                    let peer = peer_name(&peers, msg.inner.connection_id);
+                    let hash = msg.inner.request.hash;
+                    if share.exhausted(&downloads) {
+                        msg.tx.send(Err(AbortReason::Permission)).await.ok();
+                    } else if share.allows(hash, &msg.inner.request.ranges) {
+                        msg.tx.send(Ok(())).await.ok();
+                        let ranges = msg.inner.request.ranges.clone();
+                        let id = msg.inner.connection_id;
+                        let peer_id = peers.get(&id).copied();
+                        track_request(msg.rx, ranges, id, peer_id, share.clone(), downloads.clone(), sink.clone());
+                    } else {
+                        sink.add(format!("REQUEST_DENIED:{}:{}", peer, hash)).ok();
+                        msg.tx.send(Err(AbortReason::Permission)).await.ok();
+                    }
+                }
+                ProviderMessage::GetManyRequestReceived(msg) => {
+                    let peer = peer_name(&peers, msg.inner.connection_id);
+                    let hashes = &msg.inner.request.hashes;
+                    if share.exhausted(&downloads) {
+                        msg.tx.send(Err(AbortReason::Permission)).await.ok();
+                    } else if let Some(hash) = hashes.iter().find(|hash| !share.hashes.contains(*hash)) {
+                        sink.add(format!("REQUEST_DENIED:{}:{}", peer, hash)).ok();
+                        msg.tx.send(Err(AbortReason::Permission)).await.ok();
+                    } else {
+                        msg.tx.send(Ok(())).await.ok();
+                        let ranges = msg.inner.request.ranges.clone();
+                        let id = msg.inner.connection_id;
+                        let peer_id = peers.get(&id).copied();
+                        track_request(msg.rx, ranges, id, peer_id, share.clone(), downloads.clone(), sink.clone());
+                    }
+                }
+                ProviderMessage::ObserveRequestReceived(msg) => {
+                    let hash = msg.inner.request.hash;
+                    let res = if share.hashes.contains(&hash) { Ok(()) } else { Err(AbortReason::Permission) };
+                    msg.tx.send(res).await.ok();
+                }
+                // Receivers never write to our store.
+                ProviderMessage::PushRequestReceived(msg) => {
+                    let peer = peer_name(&peers, msg.inner.connection_id);
+                    sink.add(format!("REQUEST_DENIED:{}:push", peer)).ok();
+                    msg.tx.send(Err(AbortReason::Permission)).await.ok();
+                }
+                _ => {}
+            }
+        }
+    });
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iroh::SecretKey;
+
+    fn downloads() -> Downloads {
+        Downloads {
+            count: Default::default(),
+            connections: Default::default(),
+            served: Default::default(),
+        }
+    }
+
+    #[test]
+    fn download_across_connections() {
+        let downloads = downloads();
+        let peer = Some(SecretKey::from_bytes(&[1; 32]).public());
+        let other = Some(SecretKey::from_bytes(&[2; 32]).public());
+        let hash = Hash::new(b"file");
+        let size = 4 * 1024;
+        let first = [(hash, ChunkRanges::chunks(0..2), size)];
+        let second = [(hash, ChunkRanges::chunks(2..4), size)];
+
+        // Resumed on a new connection after the first one failed
+        assert!(!downloads.serve(1, peer, &first));
+        assert!(downloads.completes(peer, hash, &ChunkRanges::chunks(2..4), size));
+        assert!(downloads.serve(2, peer, &second));
+        // The second half alone doesn't make another download, for anyone
+        assert!(!downloads.serve(3, peer, &second));
+        assert!(!downloads.serve(4, other, &second));
+        // Served whole again, it does
+        assert!(downloads.serve(5, peer, &first));
+    }
+}
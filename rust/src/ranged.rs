@@ -0,0 +1,276 @@
+// Reads parts of a remote file on demand, fetching only the chunks that cover
+// them, and serves them to media players over HTTP on localhost.
+
+use crate::api::HoleError;
+use anyhow::{bail, Context, Result};
+use iroh::endpoint::Connection;
+use iroh::{Endpoint, EndpointAddr};
+use iroh_blobs::protocol::{ChunkRanges, ChunkRangesExt, GetRequest};
+use iroh_blobs::store::fs::FsStore;
+use iroh_blobs::Hash;
+use rand::RngCore;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Notify;
+
+// Bodies are fetched and written in pieces of this size.
+const BODY_CHUNK_LEN: u64 = 256 * 1024;
+
+// Running servers by URL, to stop them.
+static SERVERS: LazyLock<Mutex<HashMap<String, Arc<Notify>>>> = LazyLock::new(Default::default);
+
+pub struct RemoteFile {
+    store: FsStore,
+    endpoint: Endpoint,
+    addr: EndpointAddr,
+    hash: Hash,
+    pub name: String,
+    pub mime: String,
+    pub size: u64,
+    connection: tokio::sync::Mutex<Option<Connection>>,
+}
+
+impl RemoteFile {
+    /// Opens the blob `hash` at `addr`. Without a known size, the last chunk is
+    /// fetched to prove it.
+    pub async fn open(
+        store: FsStore,
+        endpoint: Endpoint,
+        addr: EndpointAddr,
+        hash: Hash,
+        name: String,
+        mime: String,
+        size: Option<u64>,
+    ) -> Result<Self> {
+        let mut file = Self {
+            store,
+            endpoint,
+            addr,
+            hash,
+            name,
+            mime,
+            size: 0,
+            connection: Default::default(),
+        };
+        file.size = match size {
+            Some(size) => size,
+            None => {
+                file.fetch(GetRequest::blob_ranges(hash, ChunkRanges::last_chunk())).await?;
+                let size = file.store.observe(hash).await?.validated_size();
+                size.context("Blob size unknown")?
+            }
+        };
+        Ok(file)
+    }
+
+    /// Reads up to `len` bytes at `offset`, fetching what isn't stored yet.
+    pub async fn read(&self, offset: u64, len: u64) -> Result<Vec<u8>> {
+        let end = offset.saturating_add(len).min(self.size);
+        if offset >= end {
+            return Ok(Vec::new());
+        }
+        let ranges = ChunkRanges::chunks(offset / 1024..end.div_ceil(1024));
+        self.fetch(GetRequest::blob_ranges(self.hash, ranges)).await?;
+        Ok(self.store.export_ranges(self.hash, offset..end).concatenate().await?)
+    }
+
+    async fn fetch(&self, request: GetRequest) -> Result<()> {
+        let local = self.store.remote().local_for_request(request).await?;
+        if local.is_complete() {
+            return Ok(());
+        }
+        let request = local.missing();
+        // Reuse the connection, and re-dial once if it broke in the meantime
+        for retry in [false, true] {
+            let mut connection = self.connection.lock().await;
+            if connection.is_none() {
+                *connection = Some(self.endpoint.connect(self.addr.clone(), iroh_blobs::protocol::ALPN).await?);
+            }
+            let conn = connection.clone().unwrap();
+            drop(connection);
+            match self.store.remote().execute_get(conn, request.clone()).await {
+                Ok(_) => return Ok(()),
+                Err(e) if retry => return Err(e.into()),
+                Err(_) => *self.connection.lock().await = None,
+            }
+        }
+        unreachable!()
+    }
+
+    pub async fn close(&self) {
+        self.endpoint.close().await;
+    }
+}
+
+/// Serves the file on a random localhost port until `stop` is called with
+/// the URL, which is passed to `on_ready` once the server listens. Other
+/// apps can reach the port too, so the URL has a random token they can't
+/// guess.
+pub async fn serve(file: RemoteFile, on_ready: impl FnOnce(&str)) -> Result<()> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let mut token = [0u8; 16];
+    rand::rng().fill_bytes(&mut token);
+    let prefix = format!("/{}/", hex::encode(token));
+    let url = format!("http://{}{}{}", listener.local_addr()?, prefix, percent_encode(&file.name));
+    let stop = Arc::new(Notify::new());
+    SERVERS.lock().unwrap().insert(url.clone(), stop.clone());
+    on_ready(&url);
+
+    let file = Arc::new(file);
+    loop {
+        tokio::select! {
+            _ = stop.notified() => break,
+            res = listener.accept() => {
+                let (stream, _) = res?;
+                let (file, prefix) = (file.clone(), prefix.clone());
+                tokio::spawn(async move { handle(stream, &file, &prefix).await.ok() });
+            }
+        }
+    }
+    file.close().await;
+    Ok(())
+}
+
+pub fn stop(url: &str) -> Result<()> {
+    let stop = SERVERS.lock().unwrap().remove(url).ok_or_else(|| HoleError::NotFound("No such server".into()))?;
+    stop.notify_one();
+    Ok(())
+}
+
+// Answers GET and HEAD of paths under `prefix`, with a single byte range if
+// asked for. Every connection takes one request.
+async fn handle(stream: TcpStream, file: &RemoteFile, prefix: &str) -> Result<()> {
+    let mut stream = BufReader::new(stream);
+    let mut request_line = String::new();
+    stream.read_line(&mut request_line).await?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+    let mut range = None;
+    loop {
+        let mut line = String::new();
+        if stream.read_line(&mut line).await? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("range") {
+                range = Some(value.trim().to_string());
+            }
+        }
+    }
+    let stream = stream.get_mut();
+
+    if !path.starts_with(prefix) {
+        stream.write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n").await?;
+        return Ok(());
+    }
+    if method != "GET" && method != "HEAD" {
+        stream.write_all(b"HTTP/1.1 405 Method Not Allowed\r\nContent-Length: 0\r\nConnection: close\r\n\r\n").await?;
+        return Ok(());
+    }
+    let (status, start, end) = match range.as_deref().map(|range| parse_range(range, file.size)) {
+        None => ("200 OK", 0, file.size),
+        Some(Ok((start, end))) => ("206 Partial Content", start, end),
+        Some(Err(_)) => {
+            let head = format!(
+                "HTTP/1.1 416 Range Not Satisfiable\r\nContent-Range: bytes */{}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                file.size
+            );
+            stream.write_all(head.as_bytes()).await?;
+            return Ok(());
+        }
+    };
+
+    // The type comes from the sender, it must not add headers of its own
+    let mime = if is_mime(&file.mime) { file.mime.as_str() } else { "application/octet-stream" };
+    let mut head = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nAccept-Ranges: bytes\r\nConnection: close\r\n",
+        status,
+        mime,
+        end - start
+    );
+    if start != 0 || end != file.size {
+        head += &format!("Content-Range: bytes {}-{}/{}\r\n", start, end - 1, file.size);
+    }
+    head += "\r\n";
+    stream.write_all(head.as_bytes()).await?;
+    if method == "HEAD" {
+        return Ok(());
+    }
+
+    let mut offset = start;
+    while offset < end {
+        let data = file.read(offset, BODY_CHUNK_LEN.min(end - offset)).await?;
+        if data.is_empty() {
+            bail!("Short read");
+        }
+        stream.write_all(&data).await?;
+        offset += data.len() as u64;
+    }
+    stream.flush().await?;
+    Ok(())
+}
+
+// Parses `bytes=start-end`, `bytes=start-` or `bytes=-suffix` into a
+// half-open range.
+fn parse_range(range: &str, size: u64) -> Result<(u64, u64)> {
+    let spec = range.strip_prefix("bytes=").context("Unsupported range unit")?;
+    let (start, end) = spec.split_once('-').context("Invalid range")?;
+    let (start, end) = match (start.trim(), end.trim()) {
+        ("", suffix) => {
+            let suffix: u64 = suffix.parse()?;
+            (size.saturating_sub(suffix), size)
+        }
+        (start, "") => (start.parse()?, size),
+        (start, end) => (start.parse()?, end.parse::<u64>()?.saturating_add(1).min(size)),
+    };
+    if start >= end {
+        bail!("Range not satisfiable");
+    }
+    Ok((start, end))
+}
+
+// Whether `mime` is a plain `type/subtype`, without parameters.
+fn is_mime(mime: &str) -> bool {
+    let is_token = |s: &str| {
+        !s.is_empty() && s.bytes().all(|b| b.is_ascii_alphanumeric() || b"!#$&^_.+-".contains(&b))
+    };
+    mime.split_once('/').is_some_and(|(kind, subtype)| is_token(kind) && is_token(subtype))
+}
+
+fn percent_encode(name: &str) -> String {
+    name.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges() {
+        assert_eq!(parse_range("bytes=0-99", 1000).unwrap(), (0, 100));
+        assert_eq!(parse_range("bytes=900-", 1000).unwrap(), (900, 1000));
+        assert_eq!(parse_range("bytes=-100", 1000).unwrap(), (900, 1000));
+        assert_eq!(parse_range("bytes=500-5000", 1000).unwrap(), (500, 1000));
+        assert!(parse_range("bytes=1000-", 1000).is_err());
+        assert!(parse_range("bytes=5-4", 1000).is_err());
+        assert!(parse_range("items=0-1", 1000).is_err());
+        assert!(parse_range("bytes=a-b", 1000).is_err());
+    }
+
+    #[test]
+    fn mime() {
+        assert!(is_mime("video/mp4"));
+        assert!(is_mime("application/vnd.openxmlformats-officedocument.wordprocessingml.document"));
+        assert!(!is_mime("text/html\r\nSet-Cookie: a=b"));
+        assert!(!is_mime("text/plain; charset=utf-8"));
+        assert!(!is_mime("video"));
+    }
+}
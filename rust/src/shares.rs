@@ -0,0 +1,172 @@
+// Shares by id: those being served right now, and those saved to be served
+// again after a restart, in the history database. Serving can be paused as a
+// whole while the app is in the background.
+
+use crate::api::TransferRecord;
+use crate::history;
+use anyhow::Result;
+use iroh::Endpoint;
+use iroh_blobs::{Hash, HashAndFormat};
+use redb::{ReadableTable, TableDefinition};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, LazyLock, Mutex};
+use tokio::sync::{watch, Notify};
+
+// Saved shares by id as JSON.
+const SHARES: TableDefinition<&str, &[u8]> = TableDefinition::new("shares");
+
+static RUNNING: LazyLock<Mutex<HashMap<String, Arc<Running>>>> = LazyLock::new(Default::default);
+static PAUSED: LazyLock<watch::Sender<bool>> = LazyLock::new(|| watch::Sender::new(false));
+
+/// A share being served.
+#[derive(Default)]
+pub struct Running {
+    /// Notified to stop serving.
+    pub stop: Notify,
+    /// The endpoint while bound, None while paused or binding.
+    pub endpoint: Mutex<Option<Endpoint>>,
+}
+
+/// Everything needed to serve a share again, with the same ticket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedShare {
+    pub id: String,
+    pub root: HashAndFormat,
+    pub version: u32,
+    /// Secret key of the endpoint as hex, the ticket refers to its NodeId.
+    pub secret_key: String,
+    pub hashes: Vec<Hash>,
+    pub files: Vec<Hash>,
+    pub record: TransferRecord,
+    pub allowlist: Vec<String>,
+    pub prompt_unknown_peers: bool,
+    pub max_downloads: Option<u32>,
+    /// Downloads completed so far.
+    pub downloads: u32,
+    /// Unix time in milliseconds.
+    pub expires_at_ms: Option<u64>,
+    /// The latest ticket, with the addresses the share was last served on.
+    pub ticket: Option<String>,
+}
+
+impl SavedShare {
+    /// Whether the share is over and won't be served again.
+    pub fn is_done(&self) -> bool {
+        self.expires_at_ms.is_some_and(|at| at <= history::now_ms())
+            || self.max_downloads.is_some_and(|max| self.downloads >= max)
+    }
+}
+
+/// Marks a share as served. None if it already is.
+pub fn register(id: &str) -> Option<Arc<Running>> {
+    let mut running = RUNNING.lock().unwrap();
+    if running.contains_key(id) {
+        return None;
+    }
+    let share = Arc::new(Running::default());
+    running.insert(id.to_string(), share.clone());
+    Some(share)
+}
+
+pub fn unregister(id: &str) {
+    RUNNING.lock().unwrap().remove(id);
+}
+
+pub fn is_running(id: &str) -> bool {
+    RUNNING.lock().unwrap().contains_key(id)
+}
+
+/// Stops serving a share, returning whether it was served.
+pub fn stop(id: &str) -> bool {
+    match RUNNING.lock().unwrap().remove(id) {
+        Some(share) => {
+            share.stop.notify_one();
+            true
+        }
+        None => false,
+    }
+}
+
+pub fn running() -> Vec<(String, Arc<Running>)> {
+    RUNNING.lock().unwrap().iter().map(|(id, share)| (id.clone(), share.clone())).collect()
+}
+
+/// Pauses or resumes serving of all shares, including those started later.
+pub fn set_paused(paused: bool) {
+    PAUSED.send_replace(paused);
+}
+
+pub fn paused() -> watch::Receiver<bool> {
+    PAUSED.subscribe()
+}
+
+pub async fn save(data_dir: &Path, share: SavedShare) -> Result<()> {
+    let data_dir = data_dir.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let db = history::database(&data_dir)?;
+        let txn = db.begin_write()?;
+        txn.open_table(SHARES)?.insert(share.id.as_str(), serde_json::to_vec(&share)?.as_slice())?;
+        txn.commit()?;
+        Ok(())
+    })
+    .await?
+}
+
+/// Changes a saved share, if it's still saved.
+pub async fn update(data_dir: &Path, id: &str, change: impl FnOnce(&mut SavedShare) + Send + 'static) -> Result<()> {
+    let (data_dir, id) = (data_dir.to_path_buf(), id.to_string());
+    tokio::task::spawn_blocking(move || {
+        let db = history::database(&data_dir)?;
+        let txn = db.begin_write()?;
+        {
+            let mut table = txn.open_table(SHARES)?;
+            let share = match table.get(id.as_str())? {
+                Some(value) => serde_json::from_slice::<SavedShare>(value.value()).ok(),
+                None => None,
+            };
+            if let Some(mut share) = share {
+                change(&mut share);
+                table.insert(id.as_str(), serde_json::to_vec(&share)?.as_slice())?;
+            }
+        }
+        txn.commit()?;
+        Ok(())
+    })
+    .await?
+}
+
+pub async fn list(data_dir: &Path) -> Result<Vec<SavedShare>> {
+    let data_dir = data_dir.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let db = history::database(&data_dir)?;
+        let txn = db.begin_write()?;
+        let mut shares = Vec::new();
+        {
+            let table = txn.open_table(SHARES)?;
+            for entry in table.iter()? {
+                let (_, value) = entry?;
+                if let Ok(share) = serde_json::from_slice(value.value()) {
+                    shares.push(share);
+                }
+            }
+        }
+        txn.commit()?;
+        Ok(shares)
+    })
+    .await?
+}
+
+/// Forgets a saved share, returning whether it was saved.
+pub async fn remove(data_dir: &Path, id: &str) -> Result<bool> {
+    let (data_dir, id) = (data_dir.to_path_buf(), id.to_string());
+    tokio::task::spawn_blocking(move || {
+        let db = history::database(&data_dir)?;
+        let txn = db.begin_write()?;
+        let existed = txn.open_table(SHARES)?.remove(id.as_str())?.is_some();
+        txn.commit()?;
+        Ok(existed)
+    })
+    .await?
+}
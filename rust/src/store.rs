@@ -0,0 +1,287 @@
+// The one blob store in the data dir, for sent and received content alike.
+// Tags tell them apart:
+//
+// - `send/<unix ms>/<name>` for what we shared
+// - `recv/<hash>` for what we received
+// - `pin/<hash>` for what the user wants to keep regardless
+
+use crate::api::{HoleError, TransferDirection};
+use crate::history;
+use anyhow::Result;
+use iroh_blobs::hashseq::HashSeq;
+use iroh_blobs::store::fs::FsStore;
+use futures::StreamExt;
+use iroh_blobs::api::blobs::{AddPathOptions, ImportMode};
+use iroh_blobs::api::tags::TagInfo;
+use iroh_blobs::{BlobFormat, Hash, HashAndFormat};
+use redb::ReadableTable;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+use tokio::sync::watch;
+use tokio_util::io::ReaderStream;
+
+const SEND_PREFIX: &str = "send/";
+const RECV_PREFIX: &str = "recv/";
+const PIN_PREFIX: &str = "pin/";
+
+const STORE_DIR: &str = "sendme_store";
+// The database of a store, as named by iroh-blobs.
+const OLD_DB: &str = "blobs.db";
+// Separate stores of older versions, merged into the shared one on first use.
+const OLD_STORES: [(&str, TransferDirection); 2] = [
+    ("sendme_store_send", TransferDirection::Send),
+    ("sendme_store_recv", TransferDirection::Receive),
+];
+
+// A store can only be loaded once per process, everything shares it.
+static STORES: LazyLock<tokio::sync::Mutex<HashMap<PathBuf, FsStore>>> = LazyLock::new(Default::default);
+// Migrations of older stores into each store, true once done.
+static MIGRATIONS: LazyLock<Mutex<HashMap<PathBuf, watch::Receiver<bool>>>> = LazyLock::new(Default::default);
+// Blobs, or whole old stores, that could not be migrated, until reported.
+static SKIPPED: LazyLock<Mutex<Vec<String>>> = LazyLock::new(Default::default);
+
+/// Opens the store in `data_dir`. Older stores are merged into it in the
+/// background, see `migrated`.
+pub async fn open(data_dir: &str) -> Result<FsStore> {
+    let data_dir = PathBuf::from(data_dir);
+    let path = data_dir.join(STORE_DIR);
+    let mut stores = STORES.lock().await;
+    if let Some(store) = stores.get(&path) {
+        return Ok(store.clone());
+    }
+    tokio::fs::create_dir_all(&path).await?;
+    let store = FsStore::load(&path).await?;
+    let (done, migration) = watch::channel(false);
+    MIGRATIONS.lock().unwrap().insert(path.clone(), migration);
+    tokio::spawn(migrate_all(store.clone(), data_dir, done));
+    stores.insert(path, store.clone());
+    Ok(store)
+}
+
+/// Waits until older stores in `data_dir` are merged into the shared one,
+/// then takes what could not be: hashes of blobs, e.g. of shared files
+/// moved or edited since, or names of whole stores.
+pub async fn migrated(data_dir: &str) -> Vec<String> {
+    let path = PathBuf::from(data_dir).join(STORE_DIR);
+    let migration = MIGRATIONS.lock().unwrap().get(&path).cloned();
+    if let Some(mut migration) = migration {
+        migration.wait_for(|done| *done).await.ok();
+    }
+    std::mem::take(&mut *SKIPPED.lock().unwrap())
+}
+
+// An old store that can't be migrated must not keep the app from starting,
+// nor one that can, while it does.
+async fn migrate_all(store: FsStore, data_dir: PathBuf, done: watch::Sender<bool>) {
+    for (dir, direction) in OLD_STORES {
+        let old_path = data_dir.join(dir);
+        if !tokio::fs::try_exists(&old_path).await.unwrap_or_default() {
+            continue;
+        }
+        match migrate(&store, &old_path, direction).await {
+            // Left behind it is merged again on the next start, at no cost
+            Ok(true) => tokio::fs::remove_dir_all(&old_path).await.unwrap_or_default(),
+            Ok(false) => {}
+            Err(_) => SKIPPED.lock().unwrap().push(dir.to_string()),
+        }
+    }
+    done.send_replace(true);
+}
+
+/// Tags a share we send, which keeps it and everything it refers to.
+/// Returns the name of the tag.
+pub async fn tag_sent(store: &FsStore, name: &str, root: HashAndFormat) -> Result<String> {
+    let tag = format!("{}{}/{}", SEND_PREFIX, history::now_ms(), name);
+    store.tags().set(&tag, root).await?;
+    Ok(tag)
+}
+
+/// Tags a share we receive, see `tag_sent`.
+pub async fn tag_received(store: &FsStore, root: HashAndFormat) -> Result<String> {
+    let tag = format!("{}{}", RECV_PREFIX, root.hash);
+    store.tags().set(&tag, root).await?;
+    Ok(tag)
+}
+
+/// Lists the tags, all or those starting with `prefix`, in name order.
+pub async fn list(store: &FsStore, prefix: Option<&str>) -> Result<Vec<TagInfo>> {
+    let tags = match prefix {
+        Some(prefix) => store.tags().list_prefix(prefix).await?.boxed(),
+        None => store.tags().list().await?.boxed(),
+    };
+    let tags: Vec<_> = tags.collect().await;
+    Ok(tags.into_iter().collect::<std::result::Result<_, _>>()?)
+}
+
+/// Adds a `pin/` tag for the content of another tag, which keeps the content
+/// after that tag is removed. Returns the name of the new tag.
+pub async fn pin(store: &FsStore, name: &str) -> Result<String> {
+    let info = store.tags().get(name).await?.ok_or_else(|| HoleError::NotFound("No such tag".into()))?;
+    let tag = format!("{}{}", PIN_PREFIX, info.hash);
+    store.tags().set(&tag, info.hash_and_format()).await?;
+    Ok(tag)
+}
+
+/// Removes a tag. The store runs without garbage collection, so content
+/// no other tag refers to stays on disk until the data dir is cleared.
+pub async fn remove(store: &FsStore, name: &str) -> Result<()> {
+    if store.tags().delete(name).await? == 0 {
+        return Err(HoleError::NotFound("No such tag".into()).into());
+    }
+    Ok(())
+}
+
+// Moves every complete blob of an old store over and tags what it held, so
+// it survives in the shared one. Shared files the old store referred to are
+// referred to again, everything else is copied. Partial downloads are left
+// behind, and so are blobs that don't read back as they were. Returns false
+// if the disk filled up, the old store is then kept to try again on the
+// next start.
+async fn migrate(store: &FsStore, old_path: &Path, direction: TransferDirection) -> Result<bool> {
+    let files = tokio::task::spawn_blocking({
+        let db_path = old_path.join(OLD_DB);
+        move || referenced_files(&db_path)
+    })
+    .await??;
+    let old = FsStore::load(old_path).await?;
+    let mut hashes = HashSet::new();
+    let mut kept = Vec::new();
+    let mut full = false;
+    for hash in old.list().hashes().await? {
+        match old.remote().local(HashAndFormat::raw(hash)).await {
+            Ok(local) if local.is_complete() => {}
+            Ok(_) => continue,
+            Err(_) => {
+                SKIPPED.lock().unwrap().push(hash.to_string());
+                continue;
+            }
+        }
+        let import = match files.get(&hash).and_then(|paths| paths.iter().find(|path| path.is_file())) {
+            Some(path) => store.add_path_with_opts(AddPathOptions {
+                path: path.clone(),
+                mode: ImportMode::TryReference,
+                format: BlobFormat::Raw,
+            }),
+            None => {
+                // Streamed over, so nothing but the copy takes space. A read
+                // error ends the stream early, which shows as a different hash.
+                let (mut writer, reader) = tokio::io::duplex(64 * 1024);
+                let mut blob = old.reader(hash);
+                tokio::spawn(async move { tokio::io::copy(&mut blob, &mut writer).await });
+                store.add_stream(ReaderStream::new(reader)).await
+            }
+        };
+        match import.temp_tag().await.map_err(|e| HoleError::from(anyhow::Error::from(e))) {
+            Ok(tag) if tag.hash() == hash => {
+                kept.push(tag);
+                hashes.insert(hash);
+            }
+            Err(HoleError::DiskFull(_)) => {
+                full = true;
+                break;
+            }
+            _ => SKIPPED.lock().unwrap().push(hash.to_string()),
+        }
+    }
+    old.shutdown().await?;
+    if full {
+        return Ok(false);
+    }
+
+    // Shares are sequences starting with their metadata, the rest of their
+    // blobs are children. Whatever is left over is a raw share.
+    let mut roots = Vec::new();
+    let mut children = HashSet::new();
+    for hash in &hashes {
+        let Ok(seq) = HashSeq::try_from(store.get_bytes(*hash).await?) else {
+            continue;
+        };
+        if seq.len() >= 2 && seq.iter().all(|child| hashes.contains(&child)) {
+            children.extend(seq.iter());
+            roots.push(HashAndFormat::hash_seq(*hash));
+        }
+    }
+    let loose = hashes.iter().filter(|hash| !children.contains(*hash)).map(|hash| HashAndFormat::raw(*hash));
+    let loose: Vec<_> = loose.filter(|raw| !roots.iter().any(|root| root.hash == raw.hash)).collect();
+    for root in roots.into_iter().chain(loose) {
+        match direction {
+            TransferDirection::Send => tag_sent(store, &root.hash.to_string(), root).await?,
+            TransferDirection::Receive => tag_received(store, root).await?,
+        };
+    }
+    drop(kept);
+    Ok(true)
+}
+
+
+// iroh-blobs keeps where each blob's data is in its database, but doesn't
+// tell through its API. These mirror its entries as far as needed to find
+// the files a store refers to, postcard encoded as they are.
+const OLD_BLOBS: redb::TableDefinition<Hash, OldEntry> = redb::TableDefinition::new("blobs-0");
+
+#[derive(Debug, Deserialize)]
+enum OldEntry {
+    Complete { data: OldData, _outboard: OldOutboard },
+    Partial { _size: Option<u64> },
+}
+
+#[derive(Debug, Deserialize)]
+enum OldData {
+    Inline(()),
+    Owned { _size: u64 },
+    External { paths: Vec<PathBuf>, _size: u64 },
+}
+
+#[derive(Debug, Deserialize)]
+enum OldOutboard {
+    Inline(()),
+    Owned,
+    NotNeeded,
+}
+
+impl redb::Value for OldEntry {
+    type SelfType<'a> = Option<OldEntry>;
+    type AsBytes<'a> = Vec<u8>;
+
+    fn fixed_width() -> Option<usize> {
+        None
+    }
+
+    // Unknown entries are None, they are copied like owned ones
+    fn from_bytes<'a>(data: &'a [u8]) -> Self::SelfType<'a>
+    where
+        Self: 'a,
+    {
+        postcard::from_bytes(data).ok()
+    }
+
+    fn as_bytes<'a, 'b: 'a>(_: &'a Self::SelfType<'b>) -> Self::AsBytes<'a>
+    where
+        Self: 'a,
+        Self: 'b,
+    {
+        unreachable!("old entries are only read")
+    }
+
+    fn type_name() -> redb::TypeName {
+        redb::TypeName::new("EntryState")
+    }
+}
+
+// The files an old store refers to, by blob.
+fn referenced_files(db_path: &Path) -> Result<HashMap<Hash, Vec<PathBuf>>> {
+    let db = redb::Database::open(db_path)?;
+    let txn = db.begin_read()?;
+    let table = txn.open_table(OLD_BLOBS)?;
+    let mut files = HashMap::new();
+    for entry in table.iter()? {
+        let (hash, entry) = entry?;
+        if let Some(OldEntry::Complete { data: OldData::External { paths, .. }, .. }) = entry.value() {
+            files.insert(hash.value(), paths);
+        }
+    }
+    Ok(files)
+}
+
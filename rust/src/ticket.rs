@@ -0,0 +1,121 @@
+use crate::api::HoleError;
+use anyhow::{Context, Result};
+use iroh_blobs::ticket::BlobTicket;
+use iroh_blobs::BlobFormat;
+use std::fmt;
+use std::str::FromStr;
+
+/// The share layout this build sends and the newest one it understands:
+///
+/// - 0: a raw blob without a name, told apart by its first bytes
+/// - 1: HashSeq [name, file]
+/// - 2: HashSeq [name, file, info], where info is `ShareInfo` as JSON
+pub const PROTOCOL_VERSION: u32 = 2;
+
+// Tickets from version 2 on are `hole<version>:<blob ticket>`, older ones
+// are plain blob tickets.
+const PREFIX: &str = "hole";
+
+/// The version a ticket announces for a share in the current layout.
+/// Releases from before versioning take the first blob of any sequence as
+/// the name and the second as the file. That is still right for a single
+/// unencrypted file, so its ticket stays plain and says version 1, and
+/// `ShareInfo` tells the rest. Anything else needs a prefix they reject.
+pub fn version_for(encrypted: bool, files: usize) -> u32 {
+    if encrypted || files > 1 {
+        PROTOCOL_VERSION
+    } else {
+        1
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ShareTicket {
+    pub version: u32,
+    pub blob: BlobTicket,
+}
+
+impl ShareTicket {
+    /// A ticket for a share in the given layout, `PROTOCOL_VERSION` for new ones.
+    pub fn new(version: u32, blob: BlobTicket) -> Self {
+        Self { version, blob }
+    }
+}
+
+impl fmt::Display for ShareTicket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.version < 2 {
+            write!(f, "{}", self.blob)
+        } else {
+            write!(f, "{}{}:{}", PREFIX, self.version, self.blob)
+        }
+    }
+}
+
+impl FromStr for ShareTicket {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let Some((version, blob)) = s.strip_prefix(PREFIX).and_then(|rest| rest.split_once(':')) else {
+            // Plain tickets predate versioning, or are for shares readable as
+            // if they did
+            let blob = BlobTicket::from_str(s).context(HoleError::InvalidTicket("Invalid ticket".into()))?;
+            let version = if blob.format() == BlobFormat::HashSeq { 1 } else { 0 };
+            return Ok(Self { version, blob });
+        };
+        let version: u32 = version.parse().context(HoleError::InvalidTicket("Invalid ticket".into()))?;
+        // Check before parsing the rest, newer versions may change it
+        if version > PROTOCOL_VERSION {
+            let message = "This share was sent by a newer version of the app, please update";
+            return Err(HoleError::UnsupportedVersion(message.into()).into());
+        }
+        let blob = BlobTicket::from_str(blob).context(HoleError::InvalidTicket("Invalid ticket".into()))?;
+        if blob.format() != BlobFormat::HashSeq {
+            return Err(HoleError::InvalidTicket(format!("Invalid ticket: version {} must be a sequence", version)).into());
+        }
+        Ok(Self { version, blob })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::HoleError;
+    use iroh::{EndpointAddr, SecretKey};
+    use iroh_blobs::Hash;
+
+    fn blob(format: BlobFormat) -> BlobTicket {
+        let id = SecretKey::from_bytes(&[1; 32]).public();
+        BlobTicket::new(EndpointAddr::new(id), Hash::new(b"share"), format)
+    }
+
+    #[test]
+    fn round_trip() {
+        for version in [0, 1, PROTOCOL_VERSION] {
+            let format = if version == 0 { BlobFormat::Raw } else { BlobFormat::HashSeq };
+            let ticket = ShareTicket::new(version, blob(format));
+            let parsed = ShareTicket::from_str(&ticket.to_string()).unwrap();
+            assert_eq!(parsed.version, version);
+            assert_eq!(parsed.blob, ticket.blob);
+        }
+    }
+
+    #[test]
+    fn plain_if_readable_before_versioning() {
+        assert_eq!(version_for(false, 1), 1);
+        assert_eq!(version_for(true, 1), PROTOCOL_VERSION);
+        assert_eq!(version_for(false, 2), PROTOCOL_VERSION);
+        let ticket = ShareTicket::new(version_for(false, 1), blob(BlobFormat::HashSeq));
+        assert_eq!(ticket.to_string(), ticket.blob.to_string());
+    }
+
+    #[test]
+    fn rejects() {
+        let newer = format!("{}{}:{}", PREFIX, PROTOCOL_VERSION + 1, blob(BlobFormat::HashSeq));
+        let e = ShareTicket::from_str(&newer).unwrap_err();
+        assert!(matches!(e.downcast_ref::<HoleError>(), Some(HoleError::UnsupportedVersion(_))));
+        let e = ShareTicket::from_str("not a ticket").unwrap_err();
+        assert!(matches!(e.downcast_ref::<HoleError>(), Some(HoleError::InvalidTicket(_))));
+    }
+}